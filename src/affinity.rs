@@ -0,0 +1,101 @@
+use std::io::{Error, ErrorKind};
+
+use crate::cgroup::{self, Hierarchy};
+use crate::sysfs::{optional, parse, read};
+use crate::{Cpu, CpuInfo, CpuSet, SysRoot};
+
+impl SysRoot {
+    /// Reads `Cpus_allowed_list` from `/proc/self/status`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the file could not be read or lacks a valid CPU list
+    pub fn cpus_allowed_list(&self) -> Result<CpuSet, Error> {
+        let path = self.proc().join("self/status");
+        read(&path)?
+            .lines()
+            .find_map(|line| line.strip_prefix("Cpus_allowed_list:"))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{}: no Cpus_allowed_list", path.display()),
+                )
+            })?
+            .parse()
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Reads the effective CPUs of the current process's cpuset cgroup.
+    ///
+    /// Returns `None` if the process is not in a cgroup with the cpuset controller.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/self/cgroup` or the cgroup files could not be read
+    pub fn cgroup_cpus(&self) -> Result<Option<CpuSet>, Error> {
+        for (hierarchy, dir) in cgroup::dirs(self, "cpuset")? {
+            let name = match hierarchy {
+                Hierarchy::V1 => "cpuset.effective_cpus",
+                Hierarchy::V2 => "cpuset.cpus.effective",
+            };
+
+            if let Some(cpus) = optional(parse(dir.join(name)))? {
+                return Ok(Some(cpus));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the CPUs the current process may run on according to `/proc/self/status`,
+    /// restricted to its cpuset cgroup if available.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/self/status` could not be read
+    pub fn allowed_cpus(&self) -> Result<CpuSet, Error> {
+        let allowed = self.cpus_allowed_list()?;
+
+        Ok(match self.cgroup_cpus().ok().flatten() {
+            Some(cgroup) => &allowed & &cgroup,
+            None => allowed,
+        })
+    }
+
+    /// Reads `/proc/cpuinfo` restricted to the CPUs the current process may run on.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/cpuinfo` or `/proc/self/status` could not be read
+    pub fn allowed_cpu_info(&self) -> Result<CpuInfo, Error> {
+        Ok(self.cpu_info()?.restrict(&self.allowed_cpus()?))
+    }
+}
+
+impl CpuInfo {
+    /// Returns the CPU information of the given processors only.
+    #[must_use]
+    pub fn restrict(&self, cpus: &CpuSet) -> Self {
+        let mut text = self
+            .blocks()
+            .filter(|block| {
+                Cpu::from_str(block)
+                    .processor()
+                    .is_some_and(|processor| cpus.contains(processor))
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        text.push('\n');
+        Self(text)
+    }
+}
+
+#[cfg(feature = "libc")]
+impl CpuSet {
+    /// Returns the affinity mask of the current thread as reported by `sched_getaffinity()`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the system call fails
+    pub fn affinity() -> Result<Self, Error> {
+        // SAFETY: `cpu_set_t` is a plain bit array, for which all zeroes is the empty set.
+        let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+
+        // SAFETY: The size matches the set the kernel writes to.
+        if unsafe { libc::sched_getaffinity(0, std::mem::size_of_val(&set), &raw mut set) } != 0 {
+            return Err(Error::last_os_error());
+        }
+
+        Ok(Self::from(&set))
+    }
+}
@@ -0,0 +1,340 @@
+//! Selection of [archspec](https://github.com/archspec/archspec) microarchitecture targets.
+//!
+//! The bundled database is archspec's `microarchitectures.json`,
+//! licensed under `Apache-2.0 OR MIT` by the Archspec Project Developers.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde_json::{Map, Value};
+
+use crate::Cpu;
+
+const BUNDLED: &str = include_str!("archspec/microarchitectures.json");
+const GENERIC: &str = "generic";
+const X86_64: &str = "x86_64";
+/// Features archspec names differently than the kernel, as `(archspec, kernel)` pairs.
+const KERNEL_NAMES: [(&str, &str); 1] = [("sse3", "pni")];
+
+/// A microarchitecture target as defined by archspec.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Target {
+    name: String,
+    parents: Vec<String>,
+    ancestors: BTreeSet<String>,
+    vendor: String,
+    features: BTreeSet<String>,
+    generation: Option<u64>,
+}
+
+impl Target {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the names of the targets this target is directly derived from.
+    #[must_use]
+    pub fn parents(&self) -> &[String] {
+        &self.parents
+    }
+
+    /// Returns the names of all targets this target is derived from.
+    #[must_use]
+    pub const fn ancestors(&self) -> &BTreeSet<String> {
+        &self.ancestors
+    }
+
+    /// Returns the target's vendor, which is `generic` for vendor-agnostic targets.
+    #[must_use]
+    pub fn vendor(&self) -> &str {
+        &self.vendor
+    }
+
+    #[must_use]
+    pub const fn features(&self) -> &BTreeSet<String> {
+        &self.features
+    }
+
+    #[must_use]
+    pub const fn generation(&self) -> Option<u64> {
+        self.generation
+    }
+
+    fn is_generic(&self) -> bool {
+        self.vendor == GENERIC
+    }
+
+    fn descends_from(&self, name: &str) -> bool {
+        self.name == name || self.ancestors.contains(name)
+    }
+
+    fn rank(&self) -> (usize, usize) {
+        (self.ancestors.len(), self.features.len())
+    }
+}
+
+impl Display for Target {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// An archspec microarchitecture database.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Database {
+    targets: BTreeMap<String, Target>,
+    aliases: BTreeMap<String, FeatureAlias>,
+}
+
+impl Database {
+    /// Returns the database bundled with this crate.
+    /// # Panics
+    /// Panics if the bundled database is invalid, which the tests rule out
+    #[must_use]
+    pub fn bundled() -> Self {
+        Self::from_str(BUNDLED)
+            .unwrap_or_else(|error| panic!("Bundled archspec database is invalid: {error}"))
+    }
+
+    /// Reads an archspec database from the given file.
+    /// # Errors
+    /// Returns an [`Error`] if the file could not be read or is not a valid archspec database
+    pub fn read_from(filename: impl AsRef<Path>) -> Result<Self, Error> {
+        read_to_string(filename)?.parse()
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Target> {
+        self.targets.get(name)
+    }
+
+    pub fn targets(&self) -> impl Iterator<Item = &Target> {
+        self.targets.values()
+    }
+
+    /// Returns all targets the given CPU is compatible with.
+    ///
+    /// Features missing from the CPU's flags are satisfied by the database's feature aliases,
+    /// e.g. `sse3` by `ssse3`.
+    /// Currently only x86-64 CPUs are supported.
+    #[must_use]
+    pub fn compatible(&self, cpu: &Cpu<'_>) -> Vec<&Target> {
+        let flags = cpu.flags();
+        let vendor = cpu.vendor_id().unwrap_or(GENERIC);
+        let is_x86_64 = flags.contains("lm");
+        let has_feature = |feature: &str| {
+            flags.contains(feature)
+                || KERNEL_NAMES
+                    .iter()
+                    .any(|&(name, kernel)| name == feature && flags.contains(kernel))
+        };
+
+        self.targets()
+            .filter(|target| {
+                is_x86_64
+                    && target.descends_from(X86_64)
+                    && (target.is_generic() || target.vendor == vendor)
+                    && target.features.iter().all(|feature| {
+                        has_feature(feature)
+                            || self.aliases.get(feature).is_some_and(|alias| {
+                                alias.any_of.iter().any(|feature| has_feature(feature))
+                                    || alias.families.iter().any(|family| family == X86_64)
+                            })
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the most specific target the given CPU is compatible with,
+    /// using the same selection rules as archspec.
+    #[must_use]
+    pub fn best_match(&self, cpu: &Cpu<'_>) -> Option<&Target> {
+        let candidates = self.compatible(cpu);
+        let best_generic = candidates
+            .iter()
+            .copied()
+            .filter(|target| target.is_generic())
+            .max_by_key(|target| target.rank())?;
+
+        Some(
+            candidates
+                .into_iter()
+                .filter(|target| target.ancestors.contains(&best_generic.name))
+                .max_by_key(|target| target.rank())
+                .unwrap_or(best_generic),
+        )
+    }
+
+    fn parse_target(name: &str, value: &Value) -> Result<Target, Error> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| Error::Format(format!("target {name} is not an object")))?;
+
+        Ok(Target {
+            name: name.to_string(),
+            parents: string_array(object, &format!("target {name}"), "from")?,
+            ancestors: BTreeSet::new(),
+            vendor: object
+                .get("vendor")
+                .and_then(Value::as_str)
+                .ok_or_else(|| Error::Format(format!("target {name} has no vendor")))?
+                .to_string(),
+            features: string_array(object, &format!("target {name}"), "features")?
+                .into_iter()
+                .collect(),
+            generation: object.get("generation").and_then(Value::as_u64),
+        })
+    }
+
+    fn parse_alias(name: &str, value: &Value) -> Result<FeatureAlias, Error> {
+        let context = format!("feature alias {name}");
+        let object = value
+            .as_object()
+            .ok_or_else(|| Error::Format(format!("{context} is not an object")))?;
+
+        Ok(FeatureAlias {
+            any_of: string_array(object, &context, "any_of")?,
+            families: string_array(object, &context, "families")?,
+        })
+    }
+
+    fn resolve_ancestors(&mut self) -> Result<(), Error> {
+        let names: Vec<_> = self.targets.keys().cloned().collect();
+
+        for name in names {
+            let mut ancestors = BTreeSet::new();
+            let mut pending = self.targets[&name].parents.clone();
+
+            while let Some(parent) = pending.pop() {
+                if parent == name {
+                    return Err(Error::Format(format!("target {name} derives from itself")));
+                }
+
+                let target = self.targets.get(&parent).ok_or_else(|| {
+                    Error::Format(format!(
+                        "target {name} derives from unknown target {parent}"
+                    ))
+                })?;
+
+                if ancestors.insert(parent) {
+                    pending.extend(target.parents.iter().cloned());
+                }
+            }
+
+            if let Some(target) = self.targets.get_mut(&name) {
+                target.ancestors = ancestors;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Database {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+impl FromStr for Database {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json: Value = serde_json::from_str(s)?;
+        let targets = json
+            .get("microarchitectures")
+            .and_then(Value::as_object)
+            .ok_or_else(|| Error::Format("missing microarchitectures".to_string()))?;
+        let mut database = Self {
+            targets: targets
+                .iter()
+                .map(|(name, value)| {
+                    Self::parse_target(name, value).map(|target| (name.clone(), target))
+                })
+                .collect::<Result<_, _>>()?,
+            aliases: json
+                .get("feature_aliases")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .map(|(name, value)| {
+                    Self::parse_alias(name, value).map(|alias| (name.clone(), alias))
+                })
+                .collect::<Result<_, _>>()?,
+        };
+        database.resolve_ancestors()?;
+        Ok(database)
+    }
+}
+
+/// A feature, which is considered present if any of the given features is present
+/// or the CPU belongs to any of the given families.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct FeatureAlias {
+    any_of: Vec<String>,
+    families: Vec<String>,
+}
+
+/// Errors that can occur when loading an archspec database.
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Format(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::Json(error) => write!(f, "{error}"),
+            Self::Format(message) => write!(f, "invalid archspec database: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Json(error) => Some(error),
+            Self::Format(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+fn string_array(
+    object: &Map<String, Value>,
+    context: &str,
+    key: &str,
+) -> Result<Vec<String>, Error> {
+    match object.get(key) {
+        None => Ok(Vec::new()),
+        Some(Value::String(string)) => Ok(vec![string.clone()]),
+        Some(Value::Array(array)) => array
+            .iter()
+            .map(|item| {
+                item.as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| Error::Format(format!("{context} has a non-string {key}")))
+            })
+            .collect(),
+        Some(_) => Err(Error::Format(format!("{context} has an invalid {key}"))),
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::sysfs::{cpu_dirs, indexed_dirs, optional, parse, read, CPU_DIR};
+use crate::{CpuSet, SysRoot, GIB, KIB, MIB};
+
+/// The caches of all logical processors as reported by
+/// `/sys/devices/system/cpu/cpuN/cache`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Caches(BTreeMap<usize, Vec<Cache>>);
+
+impl Caches {
+    /// Reads the caches from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the caches could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().caches()
+    }
+
+    /// Reads the caches from the given sysfs root.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the caches could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut caches = BTreeMap::new();
+
+        for (processor, path) in cpu_dirs(sysfs_root.as_ref().join(CPU_DIR))? {
+            let path = path.join("cache");
+
+            if path.is_dir() {
+                caches.insert(
+                    processor,
+                    indexed_dirs(path, "index")?
+                        .values()
+                        .map(|path| Cache::read_from(path))
+                        .collect::<Result<_, _>>()?,
+                );
+            }
+        }
+
+        Ok(Self(caches))
+    }
+
+    /// Returns the caches of the given logical processor, ordered by their index.
+    #[must_use]
+    pub fn get(&self, processor: usize) -> Option<&[Cache]> {
+        self.0.get(&processor).map(Vec::as_slice)
+    }
+
+    /// Returns the logical processors and their caches.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &[Cache])> {
+        self.0
+            .iter()
+            .map(|(&processor, caches)| (processor, caches.as_slice()))
+    }
+
+    /// Returns each physical cache once, ordered by level and type.
+    ///
+    /// Caches are considered identical if they have the same level and type
+    /// and are shared by the same logical processors.
+    #[must_use]
+    pub fn instances(&self) -> Vec<&Cache> {
+        let mut instances = BTreeMap::new();
+
+        for cache in self.0.values().flatten() {
+            instances
+                .entry((cache.level, cache.kind, &cache.shared_cpus))
+                .or_insert(cache);
+        }
+
+        instances.into_values().collect()
+    }
+}
+
+/// A CPU cache as seen by a logical processor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cache {
+    level: u8,
+    kind: CacheType,
+    size: Option<usize>,
+    ways_of_associativity: Option<usize>,
+    coherency_line_size: Option<usize>,
+    number_of_sets: Option<usize>,
+    shared_cpus: CpuSet,
+}
+
+impl Cache {
+    fn read_from(path: &Path) -> Result<Self, Error> {
+        Ok(Self {
+            level: parse(path.join("level"))?,
+            kind: parse(path.join("type"))?,
+            size: optional(read(path.join("size")))?
+                .map(|size| parse_size(&size))
+                .transpose()?,
+            ways_of_associativity: optional(parse(path.join("ways_of_associativity")))?,
+            coherency_line_size: optional(parse(path.join("coherency_line_size")))?,
+            number_of_sets: optional(parse(path.join("number_of_sets")))?,
+            shared_cpus: optional(parse(path.join("shared_cpu_list")))?.unwrap_or_default(),
+        })
+    }
+
+    #[must_use]
+    pub const fn level(&self) -> u8 {
+        self.level
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> CacheType {
+        self.kind
+    }
+
+    /// Returns the cache size in bytes.
+    #[must_use]
+    pub const fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    #[must_use]
+    pub const fn ways_of_associativity(&self) -> Option<usize> {
+        self.ways_of_associativity
+    }
+
+    /// Returns the cache line size in bytes.
+    #[must_use]
+    pub const fn coherency_line_size(&self) -> Option<usize> {
+        self.coherency_line_size
+    }
+
+    #[must_use]
+    pub const fn number_of_sets(&self) -> Option<usize> {
+        self.number_of_sets
+    }
+
+    /// Returns the logical processors sharing this cache.
+    #[must_use]
+    pub const fn shared_cpus(&self) -> &CpuSet {
+        &self.shared_cpus
+    }
+}
+
+/// The type of a CPU cache.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CacheType {
+    Data,
+    Instruction,
+    Unified,
+}
+
+impl Display for CacheType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Data => write!(f, "Data"),
+            Self::Instruction => write!(f, "Instruction"),
+            Self::Unified => write!(f, "Unified"),
+        }
+    }
+}
+
+impl FromStr for CacheType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Data" => Ok(Self::Data),
+            "Instruction" => Ok(Self::Instruction),
+            "Unified" => Ok(Self::Unified),
+            other => Err(format!("invalid cache type: {other}")),
+        }
+    }
+}
+
+impl SysRoot {
+    /// Reads the CPU caches from `/sys/devices/system/cpu`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the caches could not be read
+    pub fn caches(&self) -> Result<Caches, Error> {
+        Caches::read_from(self.sys())
+    }
+}
+
+/// Parses a sysfs cache size such as `48K`.
+fn parse_size(size: &str) -> Result<usize, Error> {
+    let (value, unit) = size
+        .find(|chr: char| !chr.is_ascii_digit())
+        .map_or((size, ""), |index| size.split_at(index));
+    let value: usize = value.parse().map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid cache size: {size}"),
+        )
+    })?;
+
+    match unit {
+        "" => Ok(value),
+        "K" => Ok(value * KIB),
+        "M" => Ok(value * MIB),
+        "G" => Ok(value * GIB),
+        _ => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid cache size: {size}"),
+        )),
+    }
+}
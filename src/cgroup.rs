@@ -0,0 +1,53 @@
+use std::io::Error;
+use std::path::{Path, PathBuf};
+
+use crate::sysfs::read;
+use crate::SysRoot;
+
+const CGROUP_DIR: &str = "fs/cgroup";
+
+/// The version of a cgroup hierarchy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Hierarchy {
+    V1,
+    V2,
+}
+
+/// Returns the cgroup directories of the current process that may contain files
+/// of the given controller, from the process's own cgroup up to the root of each hierarchy.
+///
+/// Controllers of the unified hierarchy are only listed in its root's `cgroup.controllers`,
+/// so the unified hierarchy is always included.
+pub fn dirs(sysroot: &SysRoot, controller: &str) -> Result<Vec<(Hierarchy, PathBuf)>, Error> {
+    let base = sysroot.sys().join(CGROUP_DIR);
+    // Hybrid setups mount the unified hierarchy below the v1 hierarchies.
+    let unified = Some(base.join("unified"))
+        .filter(|path| path.is_dir())
+        .unwrap_or_else(|| base.clone());
+    let mut dirs = Vec::new();
+
+    for line in read(sysroot.proc().join("self/cgroup"))?.lines() {
+        let mut fields = line.splitn(3, ':');
+        let (Some(id), Some(controllers), Some(path)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let (hierarchy, mount) = if id == "0" && controllers.is_empty() {
+            (Hierarchy::V2, unified.clone())
+        } else if controllers.split(',').any(|other| other == controller) {
+            (Hierarchy::V1, base.join(controller))
+        } else {
+            continue;
+        };
+
+        for ancestor in Path::new(path).ancestors() {
+            dirs.push((
+                hierarchy,
+                mount.join(ancestor.strip_prefix("/").unwrap_or(ancestor)),
+            ));
+        }
+    }
+
+    Ok(dirs)
+}
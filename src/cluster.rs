@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+
+use crate::sysfs::{cpu_dirs, optional, parse, CPU_DIR};
+use crate::{Cpu, CpuInfo, CpuSet, SysRoot};
+
+/// Known ARM core names by implementer and part number.
+const PARTS: [(u8, u16, &str); 38] = [
+    (0x41, 0xd03, "Cortex-A53"),
+    (0x41, 0xd04, "Cortex-A35"),
+    (0x41, 0xd05, "Cortex-A55"),
+    (0x41, 0xd06, "Cortex-A65"),
+    (0x41, 0xd07, "Cortex-A57"),
+    (0x41, 0xd08, "Cortex-A72"),
+    (0x41, 0xd09, "Cortex-A73"),
+    (0x41, 0xd0a, "Cortex-A75"),
+    (0x41, 0xd0b, "Cortex-A76"),
+    (0x41, 0xd0c, "Neoverse-N1"),
+    (0x41, 0xd0d, "Cortex-A77"),
+    (0x41, 0xd0e, "Cortex-A76AE"),
+    (0x41, 0xd40, "Neoverse-V1"),
+    (0x41, 0xd41, "Cortex-A78"),
+    (0x41, 0xd42, "Cortex-A78AE"),
+    (0x41, 0xd43, "Cortex-A65AE"),
+    (0x41, 0xd44, "Cortex-X1"),
+    (0x41, 0xd46, "Cortex-A510"),
+    (0x41, 0xd47, "Cortex-A710"),
+    (0x41, 0xd48, "Cortex-X2"),
+    (0x41, 0xd49, "Neoverse-N2"),
+    (0x41, 0xd4a, "Neoverse-E1"),
+    (0x41, 0xd4b, "Cortex-A78C"),
+    (0x41, 0xd4c, "Cortex-X1C"),
+    (0x41, 0xd4d, "Cortex-A715"),
+    (0x41, 0xd4e, "Cortex-X3"),
+    (0x41, 0xd4f, "Neoverse-V2"),
+    (0x41, 0xd80, "Cortex-A520"),
+    (0x41, 0xd81, "Cortex-A720"),
+    (0x41, 0xd82, "Cortex-X4"),
+    (0x41, 0xd84, "Neoverse-V3"),
+    (0x41, 0xd8e, "Neoverse-N3"),
+    (0x51, 0x800, "Kryo 2xx Gold"),
+    (0x51, 0x801, "Kryo 2xx Silver"),
+    (0x51, 0x802, "Kryo 3xx Gold"),
+    (0x51, 0x803, "Kryo 3xx Silver"),
+    (0x51, 0x804, "Kryo 4xx Gold"),
+    (0x51, 0x805, "Kryo 4xx Silver"),
+];
+
+/// Clusters of identical ARM cores, e.g. the big and LITTLE cores of a big.LITTLE system.
+///
+/// Clusters are ordered by ascending capacity. Without capacities, e.g. on older kernels
+/// or ACPI systems, they are ordered by implementer and part number, which says nothing
+/// about their performance, so that no big / LITTLE split is reported.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CpuClusters(Vec<CpuCluster>);
+
+impl CpuClusters {
+    /// Groups the processors by their `CPU implementer`, `CPU part` and capacity.
+    #[must_use]
+    pub fn new(cpu_info: &CpuInfo, capacities: &BTreeMap<usize, usize>) -> Self {
+        let mut clusters = BTreeMap::new();
+
+        for cpu in cpu_info.cpus() {
+            let Some(processor) = cpu.processor() else {
+                continue;
+            };
+            let capacity = capacities.get(&processor).copied();
+            let (implementer, part) = (cpu.cpu_implementer(), cpu.cpu_part());
+            clusters
+                .entry((capacity, implementer, part))
+                .or_insert_with(|| CpuCluster {
+                    implementer,
+                    part,
+                    capacity,
+                    cpus: CpuSet::new(),
+                })
+                .cpus
+                .insert(processor);
+        }
+
+        Self(clusters.into_values().collect())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CpuCluster> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the cluster the given logical processor belongs to.
+    #[must_use]
+    pub fn cluster_of(&self, processor: usize) -> Option<&CpuCluster> {
+        self.iter().find(|cluster| cluster.cpus.contains(processor))
+    }
+
+    /// Returns the processors of the lowest-capacity cluster,
+    /// or an empty set if all cores are identical or the capacities are unknown.
+    #[must_use]
+    pub fn little(&self) -> CpuSet {
+        match self.ranked() {
+            Some([little, _, ..]) => little.cpus.clone(),
+            _ => CpuSet::new(),
+        }
+    }
+
+    /// Returns the processors of all but the lowest-capacity cluster,
+    /// or an empty set if the capacities of different clusters are unknown.
+    #[must_use]
+    pub fn big(&self) -> CpuSet {
+        self.ranked()
+            .unwrap_or_default()
+            .iter()
+            .skip(usize::from(self.len() > 1))
+            .flat_map(|cluster| cluster.cpus.iter())
+            .collect()
+    }
+
+    /// Returns the processors of the highest-capacity cluster, e.g. the Cortex-X core
+    /// of a `DynamIQ` system, or an empty set if the capacities of different clusters are unknown.
+    #[must_use]
+    pub fn prime(&self) -> CpuSet {
+        self.ranked()
+            .and_then(<[CpuCluster]>::last)
+            .map(|cluster| cluster.cpus.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the clusters if they are ordered by capacity,
+    /// which only requires capacities if there are several clusters.
+    fn ranked(&self) -> Option<&[CpuCluster]> {
+        (self.len() <= 1 || self.iter().all(|cluster| cluster.capacity.is_some()))
+            .then_some(self.0.as_slice())
+    }
+}
+
+/// Identical ARM cores with the same capacity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CpuCluster {
+    implementer: Option<u8>,
+    part: Option<u16>,
+    capacity: Option<usize>,
+    cpus: CpuSet,
+}
+
+impl CpuCluster {
+    #[must_use]
+    pub const fn implementer(&self) -> Option<u8> {
+        self.implementer
+    }
+
+    #[must_use]
+    pub const fn part(&self) -> Option<u16> {
+        self.part
+    }
+
+    /// Returns the name of the core, e.g. `Cortex-A55`, if known.
+    #[must_use]
+    pub fn part_name(&self) -> Option<&'static str> {
+        part_name(self.implementer?, self.part?)
+    }
+
+    /// Returns the capacity of the cores relative to the most capable core of the system,
+    /// which has a capacity of `1024`.
+    #[must_use]
+    pub const fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    #[must_use]
+    pub const fn cpus(&self) -> &CpuSet {
+        &self.cpus
+    }
+}
+
+impl Cpu<'_> {
+    /// Returns the name of the ARM core, e.g. `Cortex-A55`, if known.
+    #[must_use]
+    pub fn cpu_part_name(&self) -> Option<&'static str> {
+        part_name(self.cpu_implementer()?, self.cpu_part()?)
+    }
+}
+
+impl SysRoot {
+    /// Reads the capacity of each logical processor from `/sys/devices/system/cpu/cpuN/cpu_capacity`.
+    ///
+    /// Processors without a capacity, e.g. on x86, are omitted.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the capacities could not be read
+    pub fn cpu_capacities(&self) -> Result<BTreeMap<usize, usize>, Error> {
+        let mut capacities = BTreeMap::new();
+
+        for (processor, path) in cpu_dirs(self.sys().join(CPU_DIR))? {
+            if let Some(capacity) = optional(parse(path.join("cpu_capacity")))? {
+                capacities.insert(processor, capacity);
+            }
+        }
+
+        Ok(capacities)
+    }
+
+    /// Groups the processors of `/proc/cpuinfo` into clusters by part and capacity.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/cpuinfo` could not be read
+    pub fn cpu_clusters(&self) -> Result<CpuClusters, Error> {
+        Ok(CpuClusters::new(
+            &self.cpu_info()?,
+            &self.cpu_capacities().unwrap_or_default(),
+        ))
+    }
+}
+
+fn part_name(implementer: u8, part: u16) -> Option<&'static str> {
+    PARTS
+        .iter()
+        .find(|&&(other_implementer, other_part, _)| {
+            other_implementer == implementer && other_part == part
+        })
+        .map(|&(_, _, name)| name)
+}
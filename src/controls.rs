@@ -0,0 +1,171 @@
+use std::fmt::{Display, Formatter};
+use std::io::Error;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::sysfs::{optional, parse, read, CPU_DIR};
+use crate::{CpuInfo, SysRoot};
+
+/// The turbo / boost and SMT configuration of a system,
+/// together with the capabilities indicated by the CPU flags.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)] // The bools mirror the CPU flags of the same name.
+pub struct CpuControls {
+    boost: Option<bool>,
+    intel_pstate_no_turbo: Option<bool>,
+    intel_pstate_status: Option<String>,
+    amd_pstate_status: Option<String>,
+    smt_control: Option<SmtControl>,
+    smt_active: Option<bool>,
+    ida: bool,
+    cpb: bool,
+    ht: bool,
+    hwp: bool,
+}
+
+impl CpuControls {
+    /// Reads the controls from `/sys` and the flags from `/proc/cpuinfo`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the controls or `/proc/cpuinfo` could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().cpu_controls()
+    }
+
+    /// Reads the controls from the given sysfs root and takes the capabilities
+    /// from the flags of the given CPU information.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the controls could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>, cpu_info: &CpuInfo) -> Result<Self, Error> {
+        let path = sysfs_root.as_ref().join(CPU_DIR);
+        let has_flag = |flag| cpu_info.cpus().any(|cpu| cpu.flags().contains(flag));
+
+        Ok(Self {
+            boost: optional(parse::<u8>(path.join("cpufreq/boost")))?.map(|boost| boost != 0),
+            intel_pstate_no_turbo: optional(parse::<u8>(path.join("intel_pstate/no_turbo")))?
+                .map(|no_turbo| no_turbo != 0),
+            intel_pstate_status: optional(read(path.join("intel_pstate/status")))?,
+            amd_pstate_status: optional(read(path.join("amd_pstate/status")))?,
+            smt_control: optional(parse(path.join("smt/control")))?,
+            smt_active: optional(parse::<u8>(path.join("smt/active")))?.map(|active| active != 0),
+            ida: has_flag("ida"),
+            cpb: has_flag("cpb"),
+            ht: has_flag("ht"),
+            hwp: has_flag("hwp"),
+        })
+    }
+
+    /// Returns whether boosting is enabled according to `cpufreq/boost`,
+    /// which is provided by e.g. `acpi-cpufreq` and `amd-pstate`.
+    #[must_use]
+    pub const fn boost(&self) -> Option<bool> {
+        self.boost
+    }
+
+    /// Returns whether turbo is disabled according to `intel_pstate/no_turbo`.
+    #[must_use]
+    pub const fn intel_pstate_no_turbo(&self) -> Option<bool> {
+        self.intel_pstate_no_turbo
+    }
+
+    /// Returns the operation mode of `intel_pstate`, i.e. `active`, `passive` or `off`.
+    #[must_use]
+    pub fn intel_pstate_status(&self) -> Option<&str> {
+        self.intel_pstate_status.as_deref()
+    }
+
+    /// Returns the operation mode of `amd-pstate`, e.g. `active`, `passive` or `guided`.
+    #[must_use]
+    pub fn amd_pstate_status(&self) -> Option<&str> {
+        self.amd_pstate_status.as_deref()
+    }
+
+    #[must_use]
+    pub const fn smt_control(&self) -> Option<SmtControl> {
+        self.smt_control
+    }
+
+    /// Returns whether sibling threads are online.
+    #[must_use]
+    pub const fn smt_active(&self) -> Option<bool> {
+        self.smt_active
+    }
+
+    /// Returns whether turbo / boost is enabled, preferring `intel_pstate` over `cpufreq/boost`.
+    #[must_use]
+    pub fn turbo_enabled(&self) -> Option<bool> {
+        self.intel_pstate_no_turbo
+            .map(|no_turbo| !no_turbo)
+            .or(self.boost)
+    }
+
+    /// Returns whether the CPU supports Intel Turbo Boost (`ida`) or AMD Core Performance Boost (`cpb`).
+    #[must_use]
+    pub const fn turbo_capable(&self) -> bool {
+        self.ida || self.cpb
+    }
+
+    /// Returns whether the CPU reports the `ht` flag for multiple threads per package.
+    #[must_use]
+    pub const fn smt_capable(&self) -> bool {
+        self.ht
+    }
+
+    /// Returns whether the CPU supports hardware P-states (`hwp`).
+    #[must_use]
+    pub const fn hwp_capable(&self) -> bool {
+        self.hwp
+    }
+}
+
+/// The state of `/sys/devices/system/cpu/smt/control`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SmtControl {
+    On,
+    Off,
+    ForceOff,
+    NotSupported,
+    NotImplemented,
+    /// The amount of threads per core, on architectures supporting partial SMT.
+    Threads(usize),
+}
+
+impl Display for SmtControl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::On => write!(f, "on"),
+            Self::Off => write!(f, "off"),
+            Self::ForceOff => write!(f, "forceoff"),
+            Self::NotSupported => write!(f, "notsupported"),
+            Self::NotImplemented => write!(f, "notimplemented"),
+            Self::Threads(threads) => write!(f, "{threads}"),
+        }
+    }
+}
+
+impl FromStr for SmtControl {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "on" => Ok(Self::On),
+            "off" => Ok(Self::Off),
+            "forceoff" => Ok(Self::ForceOff),
+            "notsupported" => Ok(Self::NotSupported),
+            "notimplemented" => Ok(Self::NotImplemented),
+            other => other
+                .parse()
+                .map(Self::Threads)
+                .map_err(|_| format!("invalid SMT control: {other}")),
+        }
+    }
+}
+
+impl SysRoot {
+    /// Reads the turbo / boost and SMT controls from `/sys/devices/system/cpu`
+    /// and the flags from `/proc/cpuinfo`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the controls or `/proc/cpuinfo` could not be read
+    pub fn cpu_controls(&self) -> Result<CpuControls, Error> {
+        CpuControls::read_from(self.sys(), &self.cpu_info()?)
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::btree_set::{self, BTreeSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::iter::Copied;
+use std::ops::{BitAnd, BitOr, Sub};
+use std::str::FromStr;
+
+use crate::{Cpu, CpuInfo};
+
+const MASK_GROUP_BITS: usize = 32;
+/// The exclusive upper bound of processors in a CPU list, well above the kernel's `NR_CPUS` limit,
+/// which keeps malformed ranges from exhausting memory.
+const MAX_CPUS: usize = 1 << 16;
+
+/// A set of logical processors.
+///
+/// Parses from and formats as a Linux CPU list such as `0-3,8-11,16`,
+/// as used by sysfs, cgroups and `taskset -c`.
+///
+/// Processors above `65535` are rejected by the parsers,
+/// while the other constructors panic on them.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CpuSet(BTreeSet<usize>);
+
+impl CpuSet {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(BTreeSet::new())
+    }
+
+    /// Parses a hexadecimal CPU mask such as `ff,ffffffff` or `0xf0`,
+    /// as used by `/proc/self/status` and `taskset`.
+    /// # Errors
+    /// Returns a [`ParseCpuSetError`] if the mask contains non-hexadecimal characters
+    /// or processors above `65535`
+    pub fn from_mask(mask: &str) -> Result<Self, ParseCpuSetError> {
+        let trimmed = mask.trim();
+        let digits = trimmed
+            .strip_prefix("0x")
+            .or_else(|| trimmed.strip_prefix("0X"))
+            .unwrap_or(trimmed);
+        let mut cpus = BTreeSet::new();
+
+        for (index, digit) in digits.chars().rev().filter(|&chr| chr != ',').enumerate() {
+            let value = digit
+                .to_digit(16)
+                .filter(|&value| value == 0 || index * 4 < MAX_CPUS)
+                .ok_or_else(|| ParseCpuSetError::InvalidMask(mask.to_string()))?;
+            cpus.extend(
+                (0..4)
+                    .filter(|bit| value & (1 << bit) != 0)
+                    .map(|bit| index * 4 + bit),
+            );
+        }
+
+        Ok(Self(cpus))
+    }
+
+    /// Formats the set as a hexadecimal CPU mask in comma-separated groups of 32 bits,
+    /// as used by `/proc/self/status`.
+    #[must_use]
+    pub fn to_mask(&self) -> String {
+        let Some(&last) = self.0.last() else {
+            return "0".to_string();
+        };
+        let mut groups = vec![0u32; last / MASK_GROUP_BITS + 1];
+
+        for &cpu in &self.0 {
+            groups[cpu / MASK_GROUP_BITS] |= 1 << (cpu % MASK_GROUP_BITS);
+        }
+
+        groups
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(index, group)| {
+                if index == 0 {
+                    format!("{group:x}")
+                } else {
+                    format!("{group:08x}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Adds a processor to the set and returns whether it was newly inserted.
+    /// # Panics
+    /// Panics if the processor is above `65535`
+    pub fn insert(&mut self, cpu: usize) -> bool {
+        self.0.insert(checked(cpu))
+    }
+
+    /// Removes a processor from the set and returns whether it was present.
+    pub fn remove(&mut self, cpu: usize) -> bool {
+        self.0.remove(&cpu)
+    }
+
+    #[must_use]
+    pub fn contains(&self, cpu: usize) -> bool {
+        self.0.contains(&cpu)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the lowest processor of the set.
+    #[must_use]
+    pub fn first(&self) -> Option<usize> {
+        self.0.first().copied()
+    }
+
+    /// Returns the highest processor of the set.
+    #[must_use]
+    pub fn last(&self) -> Option<usize> {
+        self.0.last().copied()
+    }
+
+    /// Returns the processors in ascending order.
+    pub fn iter(&self) -> Copied<btree_set::Iter<'_, usize>> {
+        self.0.iter().copied()
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self(self.0.union(&other.0).copied().collect())
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self(self.0.intersection(&other.0).copied().collect())
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        Self(self.0.difference(&other.0).copied().collect())
+    }
+
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.is_subset(&other.0)
+    }
+
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.0.is_disjoint(&other.0)
+    }
+
+    /// Returns the contiguous ranges of processors in ascending order.
+    fn ranges(&self) -> Vec<(usize, usize)> {
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+        for cpu in self {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == cpu => *end = cpu,
+                _ => ranges.push((cpu, cpu)),
+            }
+        }
+
+        ranges
+    }
+}
+
+impl Display for CpuSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, (start, end)) in self.ranges().into_iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+
+            if start == end {
+                write!(f, "{start}")?;
+            } else {
+                write!(f, "{start}-{end}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for CpuSet {
+    type Err = ParseCpuSetError;
+
+    fn from_str(list: &str) -> Result<Self, Self::Err> {
+        let mut cpus = BTreeSet::new();
+
+        for entry in list.trim().split(',').filter(|entry| !entry.is_empty()) {
+            let invalid = || ParseCpuSetError::InvalidEntry(entry.to_string());
+            let index = |s: &str| {
+                s.trim()
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&index| index < MAX_CPUS)
+                    .ok_or_else(invalid)
+            };
+
+            match entry.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (index(start)?, index(end)?);
+
+                    if start > end {
+                        return Err(invalid());
+                    }
+
+                    cpus.extend(start..=end);
+                }
+                None => {
+                    cpus.insert(index(entry)?);
+                }
+            }
+        }
+
+        Ok(Self(cpus))
+    }
+}
+
+impl FromIterator<usize> for CpuSet {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        Self(iter.into_iter().map(checked).collect())
+    }
+}
+
+impl Extend<usize> for CpuSet {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+        self.0.extend(iter.into_iter().map(checked));
+    }
+}
+
+impl IntoIterator for CpuSet {
+    type Item = usize;
+    type IntoIter = btree_set::IntoIter<usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'set> IntoIterator for &'set CpuSet {
+    type Item = usize;
+    type IntoIter = Copied<btree_set::Iter<'set, usize>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize> From<[usize; N]> for CpuSet {
+    fn from(cpus: [usize; N]) -> Self {
+        cpus.into_iter().collect()
+    }
+}
+
+impl From<BTreeSet<usize>> for CpuSet {
+    fn from(cpus: BTreeSet<usize>) -> Self {
+        if let Some(&cpu) = cpus.last() {
+            checked(cpu);
+        }
+
+        Self(cpus)
+    }
+}
+
+impl From<CpuSet> for BTreeSet<usize> {
+    fn from(cpus: CpuSet) -> Self {
+        cpus.0
+    }
+}
+
+impl BitOr for &CpuSet {
+    type Output = CpuSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for &CpuSet {
+    type Output = CpuSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for &CpuSet {
+    type Output = CpuSet;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl CpuInfo {
+    /// Returns the processors of all CPUs matching the given predicate,
+    /// e.g. `cpu_info.cpu_set(|cpu| cpu.core_id() == Some(0))`.
+    #[must_use]
+    pub fn cpu_set(&self, predicate: impl Fn(&Cpu) -> bool) -> CpuSet {
+        self.cpus()
+            .filter(|cpu| predicate(cpu))
+            .filter_map(|cpu| cpu.processor())
+            .collect()
+    }
+}
+
+#[cfg(feature = "libc")]
+impl From<&libc::cpu_set_t> for CpuSet {
+    fn from(set: &libc::cpu_set_t) -> Self {
+        (0..cpu_setsize())
+            // SAFETY: The index is within the bounds of the set.
+            .filter(|&cpu| unsafe { libc::CPU_ISSET(cpu, set) })
+            .collect()
+    }
+}
+
+#[cfg(feature = "libc")]
+impl TryFrom<&CpuSet> for libc::cpu_set_t {
+    type Error = std::io::Error;
+
+    /// Converts the set into a `cpu_set_t` for use with `sched_setaffinity()`.
+    ///
+    /// Fails with [`std::io::ErrorKind::InvalidInput`] if the set contains processors
+    /// beyond `CPU_SETSIZE`.
+    fn try_from(cpus: &CpuSet) -> Result<Self, Self::Error> {
+        if let Some(cpu) = cpus.last().filter(|&cpu| cpu >= cpu_setsize()) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("processor {cpu} exceeds CPU_SETSIZE"),
+            ));
+        }
+
+        // SAFETY: `cpu_set_t` is a plain bit array, for which all zeroes is the empty set.
+        let mut set: Self = unsafe { std::mem::zeroed() };
+
+        for cpu in cpus {
+            // SAFETY: The index has been checked against the bounds of the set above.
+            unsafe { libc::CPU_SET(cpu, &mut set) };
+        }
+
+        Ok(set)
+    }
+}
+
+#[cfg(feature = "libc")]
+fn cpu_setsize() -> usize {
+    usize::try_from(libc::CPU_SETSIZE).unwrap_or_default()
+}
+
+/// Asserts that the processor is below [`MAX_CPUS`], so that e.g. [`CpuSet::to_mask`]
+/// does not allocate arbitrary amounts of memory.
+fn checked(cpu: usize) -> usize {
+    assert!(
+        cpu < MAX_CPUS,
+        "processor {cpu} exceeds the maximum of 65535"
+    );
+    cpu
+}
+
+/// An error while parsing a [`CpuSet`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseCpuSetError {
+    InvalidEntry(String),
+    InvalidMask(String),
+}
+
+impl Display for ParseCpuSetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidEntry(entry) => write!(f, "invalid CPU list entry: {entry}"),
+            Self::InvalidMask(mask) => write!(f, "invalid CPU mask: {mask}"),
+        }
+    }
+}
+
+impl Error for ParseCpuSetError {}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use crate::sysfs::{optional, read, CPU_DIR};
+use crate::{Cpu, CpuInfo, CpuSet, SysRoot};
+
+/// The CPU sets of `/sys/devices/system/cpu`, which tell why a processor
+/// may be missing from `/proc/cpuinfo`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CpuStates {
+    online: CpuSet,
+    offline: CpuSet,
+    possible: CpuSet,
+    present: CpuSet,
+    isolated: CpuSet,
+    nohz_full: CpuSet,
+}
+
+impl CpuStates {
+    /// Reads the CPU sets from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the CPU sets could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().cpu_states()
+    }
+
+    /// Reads the CPU sets from the given sysfs root.
+    ///
+    /// Missing `offline`, `isolated` and `nohz_full` files, e.g. on older kernels,
+    /// are read as empty sets, as are files containing `(null)`, which kernels with
+    /// `CONFIG_CPUMASK_OFFSTACK` report for `nohz_full` if it is not configured.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the CPU sets could not be read,
+    /// including if `online`, `possible` or `present` is missing
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = sysfs_root.as_ref().join(CPU_DIR);
+        let cpus = |name, required| {
+            let path = path.join(name);
+            let list = read(&path);
+            let list = if required {
+                Some(list?)
+            } else {
+                optional(list)?
+            };
+
+            match list.as_deref() {
+                None | Some("(null)") => Ok(CpuSet::default()),
+                Some(list) => list.parse().map_err(|error| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("{}: {error}", path.display()),
+                    )
+                }),
+            }
+        };
+
+        Ok(Self {
+            online: cpus("online", true)?,
+            offline: cpus("offline", false)?,
+            possible: cpus("possible", true)?,
+            present: cpus("present", true)?,
+            isolated: cpus("isolated", false)?,
+            nohz_full: cpus("nohz_full", false)?,
+        })
+    }
+
+    /// Returns the processors currently online.
+    #[must_use]
+    pub const fn online(&self) -> &CpuSet {
+        &self.online
+    }
+
+    /// Returns the processors currently offline, including those not present.
+    #[must_use]
+    pub const fn offline(&self) -> &CpuSet {
+        &self.offline
+    }
+
+    /// Returns the processors the kernel has allocated resources for,
+    /// including those that may be hotplugged later.
+    #[must_use]
+    pub const fn possible(&self) -> &CpuSet {
+        &self.possible
+    }
+
+    /// Returns the processors physically present in the system.
+    #[must_use]
+    pub const fn present(&self) -> &CpuSet {
+        &self.present
+    }
+
+    /// Returns the processors isolated from scheduling by `isolcpus`.
+    #[must_use]
+    pub const fn isolated(&self) -> &CpuSet {
+        &self.isolated
+    }
+
+    /// Returns the adaptive-ticks processors configured by `nohz_full`.
+    #[must_use]
+    pub const fn nohz_full(&self) -> &CpuSet {
+        &self.nohz_full
+    }
+
+    /// Returns the hotplug state of the given processor.
+    #[must_use]
+    pub fn state(&self, processor: usize) -> CpuState {
+        if self.online.contains(processor) {
+            CpuState::Online
+        } else if self.present.contains(processor) {
+            CpuState::Offline
+        } else if self.possible.contains(processor) {
+            CpuState::NotPresent
+        } else {
+            CpuState::Impossible
+        }
+    }
+
+    /// Returns the state of all possible, present and online processors.
+    #[must_use]
+    pub fn states(&self) -> BTreeMap<usize, CpuState> {
+        self.possible
+            .iter()
+            .chain(&self.present)
+            .chain(&self.online)
+            .map(|processor| (processor, self.state(processor)))
+            .collect()
+    }
+
+    /// Annotates the state of each processor with its CPU from `/proc/cpuinfo`, if listed there.
+    #[must_use]
+    pub fn annotate<'cpu_info>(
+        &self,
+        cpu_info: &'cpu_info CpuInfo,
+    ) -> BTreeMap<usize, (CpuState, Option<Cpu<'cpu_info>>)> {
+        let mut cpus: BTreeMap<_, _> = cpu_info
+            .cpus()
+            .filter_map(|cpu| cpu.processor().map(|processor| (processor, cpu)))
+            .collect();
+        let mut states: BTreeMap<_, _> = self
+            .states()
+            .into_iter()
+            .map(|(processor, state)| (processor, (state, cpus.remove(&processor))))
+            .collect();
+
+        for (processor, cpu) in cpus {
+            states.insert(processor, (self.state(processor), Some(cpu)));
+        }
+
+        states
+    }
+}
+
+/// The hotplug state of a logical processor.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CpuState {
+    /// The processor is online and thus listed in `/proc/cpuinfo`.
+    Online,
+    /// The processor is present, but has been taken offline.
+    Offline,
+    /// The processor could be hotplugged, but is not present.
+    NotPresent,
+    /// The processor does not exist.
+    Impossible,
+}
+
+impl Display for CpuState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Online => write!(f, "online"),
+            Self::Offline => write!(f, "offline"),
+            Self::NotPresent => write!(f, "not present"),
+            Self::Impossible => write!(f, "impossible"),
+        }
+    }
+}
+
+impl SysRoot {
+    /// Reads the online, offline, possible, present, isolated and `nohz_full` CPU sets
+    /// from `/sys/devices/system/cpu`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the CPU sets could not be read
+    pub fn cpu_states(&self) -> Result<CpuStates, Error> {
+        CpuStates::read_from(self.sys())
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::path::Path;
+
+use crate::sysfs::{cpu_dirs, optional, parse, read, CPU_DIR};
+use crate::{Cpu, SysRoot};
+
+/// The frequency scaling state of all logical processors as reported by
+/// `/sys/devices/system/cpu/cpuN/cpufreq`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CpuFreqs(BTreeMap<usize, CpuFreq>);
+
+impl CpuFreqs {
+    /// Reads the frequency scaling state from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the frequency scaling state could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().cpufreqs()
+    }
+
+    /// Reads the frequency scaling state from the given sysfs root.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the frequency scaling state could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut cpufreqs = BTreeMap::new();
+
+        for (processor, path) in cpu_dirs(sysfs_root.as_ref().join(CPU_DIR))? {
+            let path = path.join("cpufreq");
+
+            // Processors without a cpufreq driver, e.g. in VMs, have no cpufreq directory.
+            if path.is_dir() {
+                cpufreqs.insert(processor, CpuFreq::read_from(processor, &path)?);
+            }
+        }
+
+        Ok(Self(cpufreqs))
+    }
+
+    #[must_use]
+    pub fn get(&self, processor: usize) -> Option<&CpuFreq> {
+        self.0.get(&processor)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CpuFreq> {
+        self.0.values()
+    }
+}
+
+/// The frequency scaling state of a single logical processor.
+///
+/// All frequencies are in kHz.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CpuFreq {
+    processor: usize,
+    min_freq: Option<u64>,
+    max_freq: Option<u64>,
+    base_frequency: Option<u64>,
+    cur_freq: Option<u64>,
+    governor: Option<String>,
+    driver: Option<String>,
+    energy_performance_preference: Option<String>,
+}
+
+impl CpuFreq {
+    fn read_from(processor: usize, path: &Path) -> Result<Self, Error> {
+        Ok(Self {
+            processor,
+            min_freq: optional(parse(path.join("cpuinfo_min_freq")))?,
+            max_freq: optional(parse(path.join("cpuinfo_max_freq")))?,
+            base_frequency: optional(parse(path.join("base_frequency")))?,
+            cur_freq: optional(parse(path.join("scaling_cur_freq")))?,
+            governor: optional(read(path.join("scaling_governor")))?,
+            driver: optional(read(path.join("scaling_driver")))?,
+            energy_performance_preference: optional(read(
+                path.join("energy_performance_preference"),
+            ))?,
+        })
+    }
+
+    #[must_use]
+    pub const fn processor(&self) -> usize {
+        self.processor
+    }
+
+    /// Returns the minimum frequency supported by the hardware.
+    #[must_use]
+    pub const fn min_freq(&self) -> Option<u64> {
+        self.min_freq
+    }
+
+    /// Returns the maximum frequency supported by the hardware, including boost frequencies.
+    #[must_use]
+    pub const fn max_freq(&self) -> Option<u64> {
+        self.max_freq
+    }
+
+    /// Returns the guaranteed base frequency, which is only reported by `intel_pstate`.
+    #[must_use]
+    pub const fn base_frequency(&self) -> Option<u64> {
+        self.base_frequency
+    }
+
+    /// Returns the current frequency as last determined by the kernel.
+    #[must_use]
+    pub const fn cur_freq(&self) -> Option<u64> {
+        self.cur_freq
+    }
+
+    /// Returns the scaling governor, e.g. `powersave` or `performance`.
+    #[must_use]
+    pub fn governor(&self) -> Option<&str> {
+        self.governor.as_deref()
+    }
+
+    /// Returns the scaling driver, e.g. `intel_pstate` or `acpi-cpufreq`.
+    #[must_use]
+    pub fn driver(&self) -> Option<&str> {
+        self.driver.as_deref()
+    }
+
+    /// Returns the energy performance preference (EPP), e.g. `balance_performance`.
+    #[must_use]
+    pub fn energy_performance_preference(&self) -> Option<&str> {
+        self.energy_performance_preference.as_deref()
+    }
+}
+
+impl Cpu<'_> {
+    /// Returns the CPU's frequency scaling state.
+    #[must_use]
+    pub fn cpufreq<'cpufreqs>(&self, cpufreqs: &'cpufreqs CpuFreqs) -> Option<&'cpufreqs CpuFreq> {
+        self.processor()
+            .and_then(|processor| cpufreqs.get(processor))
+    }
+}
+
+impl SysRoot {
+    /// Reads the frequency scaling state from `/sys/devices/system/cpu`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the frequency scaling state could not be read
+    pub fn cpufreqs(&self) -> Result<CpuFreqs, Error> {
+        CpuFreqs::read_from(self.sys())
+    }
+}
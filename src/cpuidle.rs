@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::path::Path;
+
+use crate::sysfs::{cpu_dirs, indexed_dirs, optional, parse, read, CPU_DIR};
+use crate::{Cpu, SysRoot};
+
+/// The idle states of all logical processors as reported by
+/// `/sys/devices/system/cpu/cpuN/cpuidle`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CpuIdles(BTreeMap<usize, CpuIdle>);
+
+impl CpuIdles {
+    /// Reads the idle states from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the idle states could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().cpuidles()
+    }
+
+    /// Reads the idle states from the given sysfs root.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the idle states could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut cpuidles = BTreeMap::new();
+
+        for (processor, path) in cpu_dirs(sysfs_root.as_ref().join(CPU_DIR))? {
+            let path = path.join("cpuidle");
+
+            if path.is_dir() {
+                cpuidles.insert(
+                    processor,
+                    CpuIdle {
+                        processor,
+                        states: indexed_dirs(path, "state")?
+                            .into_iter()
+                            .map(|(index, path)| IdleState::read_from(index, &path))
+                            .collect::<Result<_, _>>()?,
+                    },
+                );
+            }
+        }
+
+        Ok(Self(cpuidles))
+    }
+
+    #[must_use]
+    pub fn get(&self, processor: usize) -> Option<&CpuIdle> {
+        self.0.get(&processor)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CpuIdle> {
+        self.0.values()
+    }
+}
+
+/// The idle states of a single logical processor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CpuIdle {
+    processor: usize,
+    states: Vec<IdleState>,
+}
+
+impl CpuIdle {
+    #[must_use]
+    pub const fn processor(&self) -> usize {
+        self.processor
+    }
+
+    /// Returns the idle states ordered by their index, i.e. from the shallowest to the deepest.
+    #[must_use]
+    pub fn states(&self) -> &[IdleState] {
+        &self.states
+    }
+
+    /// Returns the deepest idle state, which is not disabled.
+    #[must_use]
+    pub fn deepest_enabled(&self) -> Option<&IdleState> {
+        self.states.iter().rev().find(|state| !state.disabled)
+    }
+
+    /// Returns the total time spent in all idle states in microseconds.
+    #[must_use]
+    pub fn total_time(&self) -> u64 {
+        self.states.iter().map(|state| state.time).sum()
+    }
+}
+
+/// An idle state (C-state) of a logical processor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdleState {
+    index: usize,
+    name: String,
+    desc: Option<String>,
+    latency: u64,
+    residency: u64,
+    disabled: bool,
+    usage: u64,
+    time: u64,
+}
+
+impl IdleState {
+    fn read_from(index: usize, path: &Path) -> Result<Self, Error> {
+        Ok(Self {
+            index,
+            name: read(path.join("name"))?,
+            desc: optional(read(path.join("desc")))?,
+            latency: parse(path.join("latency"))?,
+            residency: parse(path.join("residency"))?,
+            disabled: optional(parse::<u8>(path.join("disable")))?
+                .is_some_and(|disable| disable != 0),
+            usage: parse(path.join("usage"))?,
+            time: parse(path.join("time"))?,
+        })
+    }
+
+    /// Returns the index `K` of `stateK`.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the name of the state, e.g. `C6`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn desc(&self) -> Option<&str> {
+        self.desc.as_deref()
+    }
+
+    /// Returns the exit latency in microseconds.
+    #[must_use]
+    pub const fn latency(&self) -> u64 {
+        self.latency
+    }
+
+    /// Returns the target residency in microseconds, i.e. the minimum time to spend in the state
+    /// for it to save energy.
+    #[must_use]
+    pub const fn residency(&self) -> u64 {
+        self.residency
+    }
+
+    #[must_use]
+    pub const fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns how often the state was entered.
+    #[must_use]
+    pub const fn usage(&self) -> u64 {
+        self.usage
+    }
+
+    /// Returns the total time spent in the state in microseconds.
+    #[must_use]
+    pub const fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Cpu<'_> {
+    /// Returns the CPU's idle states.
+    #[must_use]
+    pub fn cpuidle<'cpuidles>(&self, cpuidles: &'cpuidles CpuIdles) -> Option<&'cpuidles CpuIdle> {
+        self.processor()
+            .and_then(|processor| cpuidles.get(processor))
+    }
+}
+
+impl SysRoot {
+    /// Reads the idle states from `/sys/devices/system/cpu`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the idle states could not be read
+    pub fn cpuidles(&self) -> Result<CpuIdles, Error> {
+        CpuIdles::read_from(self.sys())
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::fs::read_dir;
+use std::io::Error;
+use std::path::Path;
+
+use crate::sysfs::{indexed_dirs, optional, parse, read};
+use crate::{Cpu, SysRoot};
+
+const HWMON_DIR: &str = "class/hwmon";
+const DRIVERS: [&str; 3] = ["coretemp", "k10temp", "zenpower"];
+
+/// CPU temperatures by socket as reported by the `coretemp`, `k10temp` and `zenpower`
+/// devices of `/sys/class/hwmon`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Temperatures(BTreeMap<usize, SocketTemperatures>);
+
+impl Temperatures {
+    /// Reads the CPU temperatures from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the temperatures could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().temperatures()
+    }
+
+    /// Reads the CPU temperatures from the given sysfs root.
+    ///
+    /// Devices without a `Package id X` label, such as those of AMD CPUs,
+    /// are assigned to sockets in the order of their hwmon index.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the temperatures could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut sockets = BTreeMap::new();
+        let mut index = 0;
+
+        for path in indexed_dirs(sysfs_root.as_ref().join(HWMON_DIR), "hwmon")?.values() {
+            if !optional(read(path.join("name")))?
+                .is_some_and(|name| DRIVERS.contains(&name.as_str()))
+            {
+                continue;
+            }
+
+            let mut socket = SocketTemperatures::default();
+            let mut id = None;
+
+            for sensor in temperatures(path)? {
+                match sensor.label.as_deref().map(Label::from) {
+                    Some(Label::Package(package)) => {
+                        id = Some(package);
+                        socket.package = Some(sensor);
+                    }
+                    Some(Label::Core(core)) => {
+                        socket.cores.insert(core, sensor);
+                    }
+                    _ => socket.sensors.push(sensor),
+                }
+            }
+
+            // AMD CPUs report the die temperature as `Tdie` or the control temperature as `Tctl`.
+            if socket.package.is_none() {
+                socket.package = ["Tdie", "Tctl"].iter().find_map(|label| {
+                    socket
+                        .sensors
+                        .iter()
+                        .find(|sensor| sensor.label() == Some(label))
+                        .cloned()
+                });
+            }
+
+            sockets.insert(id.unwrap_or(index), socket);
+            index += 1;
+        }
+
+        Ok(Self(sockets))
+    }
+
+    pub fn sockets(&self) -> impl Iterator<Item = (usize, &SocketTemperatures)> {
+        self.0.iter().map(|(&id, socket)| (id, socket))
+    }
+
+    /// Returns the temperatures of the socket with the given `physical id`.
+    #[must_use]
+    pub fn socket(&self, physical_id: usize) -> Option<&SocketTemperatures> {
+        self.0.get(&physical_id)
+    }
+
+    /// Returns the package temperature of the socket with the given `physical id`.
+    #[must_use]
+    pub fn package(&self, physical_id: usize) -> Option<&Temperature> {
+        self.socket(physical_id)?.package()
+    }
+
+    /// Returns the temperature of the core with the given `physical id` and `core id`.
+    #[must_use]
+    pub fn core(&self, physical_id: usize, core_id: usize) -> Option<&Temperature> {
+        self.socket(physical_id)?.core(core_id)
+    }
+}
+
+/// The temperatures of a single socket.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SocketTemperatures {
+    package: Option<Temperature>,
+    cores: BTreeMap<usize, Temperature>,
+    sensors: Vec<Temperature>,
+}
+
+impl SocketTemperatures {
+    /// Returns the package temperature, which is `Tdie` or `Tctl` on AMD CPUs.
+    #[must_use]
+    pub const fn package(&self) -> Option<&Temperature> {
+        self.package.as_ref()
+    }
+
+    /// Returns the temperature of the core with the given `core id`.
+    #[must_use]
+    pub fn core(&self, core_id: usize) -> Option<&Temperature> {
+        self.cores.get(&core_id)
+    }
+
+    /// Returns the core temperatures by `core id`.
+    #[must_use]
+    pub const fn cores(&self) -> &BTreeMap<usize, Temperature> {
+        &self.cores
+    }
+
+    /// Returns the sensors other than package and cores, e.g. `Tctl` or `Tccd1`.
+    #[must_use]
+    pub fn sensors(&self) -> &[Temperature] {
+        &self.sensors
+    }
+}
+
+/// A temperature sensor with its readings in degrees Celsius.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Temperature {
+    label: Option<String>,
+    current: Option<f64>,
+    max: Option<f64>,
+    crit: Option<f64>,
+}
+
+impl Temperature {
+    fn read_from(path: &Path, index: usize) -> Result<Self, Error> {
+        let celsius = |name: &str| {
+            optional(parse::<i32>(path.join(format!("temp{index}_{name}"))))
+                .map(|millis| millis.map(|millis| f64::from(millis) / 1000.0))
+        };
+
+        Ok(Self {
+            label: optional(read(path.join(format!("temp{index}_label"))))?,
+            current: celsius("input")?,
+            max: celsius("max")?,
+            crit: celsius("crit")?,
+        })
+    }
+
+    /// Returns the sensor's label, e.g. `Package id 0`, `Core 4` or `Tctl`.
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    #[must_use]
+    pub const fn current(&self) -> Option<f64> {
+        self.current
+    }
+
+    /// Returns the temperature at which the CPU starts to throttle.
+    #[must_use]
+    pub const fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// Returns the temperature at which the system shuts down.
+    #[must_use]
+    pub const fn crit(&self) -> Option<f64> {
+        self.crit
+    }
+}
+
+impl Cpu<'_> {
+    /// Returns the temperature of the CPU's core, falling back to its package temperature.
+    ///
+    /// CPUs without a `physical id` are considered to be on socket `0`.
+    #[must_use]
+    pub fn temperature<'temperatures>(
+        &self,
+        temperatures: &'temperatures Temperatures,
+    ) -> Option<&'temperatures Temperature> {
+        let socket = temperatures.socket(self.physical_id().unwrap_or_default())?;
+        self.core_id()
+            .and_then(|core_id| socket.core(core_id))
+            .or_else(|| socket.package())
+    }
+}
+
+impl SysRoot {
+    /// Reads the CPU temperatures from `/sys/class/hwmon`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the temperatures could not be read
+    pub fn temperatures(&self) -> Result<Temperatures, Error> {
+        Temperatures::read_from(self.sys())
+    }
+}
+
+/// The meaning of a `coretemp` label.
+enum Label {
+    Package(usize),
+    Core(usize),
+    Other,
+}
+
+impl From<&str> for Label {
+    fn from(label: &str) -> Self {
+        let id = |prefix| label.strip_prefix(prefix).and_then(|id| id.parse().ok());
+
+        id("Package id ")
+            .map(Self::Package)
+            .or_else(|| id("Core ").map(Self::Core))
+            .unwrap_or(Self::Other)
+    }
+}
+
+/// Reads all `tempN_*` sensors of a hwmon device, ordered by their index.
+fn temperatures(path: &Path) -> Result<Vec<Temperature>, Error> {
+    let mut indices = Vec::new();
+
+    for entry in read_dir(path)? {
+        if let Some(index) = entry?
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix("temp")?.strip_suffix("_input"))
+            .and_then(|index| index.parse().ok())
+        {
+            indices.push(index);
+        }
+    }
+
+    indices.sort_unstable();
+    indices
+        .into_iter()
+        .map(|index| Temperature::read_from(path, index))
+        .collect()
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::Error;
+use std::path::Path;
+
+use crate::sysfs::{optional, parse};
+use crate::{CacheType, Caches, Cpu, CpuInfo, CpuSet, Socket, SysRoot, Topology};
+
+const HYBRID_FLAG: &str = "hybrid_cpu";
+const DEVICES_DIR: &str = "devices";
+
+/// The type of a physical core on hybrid CPUs such as Intel Alder Lake.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CoreType {
+    /// A performance core (P-core).
+    Performance,
+    /// An efficiency core (E-core).
+    Efficiency,
+}
+
+impl Display for CoreType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Performance => write!(f, "P-core"),
+            Self::Efficiency => write!(f, "E-core"),
+        }
+    }
+}
+
+/// The core types of the logical processors of a hybrid CPU.
+///
+/// This is empty on CPUs with a single core type.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CoreTypes(BTreeMap<usize, CoreType>);
+
+impl CoreTypes {
+    /// Reads the core types from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the core types could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().core_types()
+    }
+
+    /// Reads the core types from the PMU devices `cpu_core` and `cpu_atom` below the given sysfs root.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the PMU devices' CPU lists could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = sysfs_root.as_ref().join(DEVICES_DIR);
+        let mut core_types = BTreeMap::new();
+
+        for (device, core_type) in [
+            ("cpu_core", CoreType::Performance),
+            ("cpu_atom", CoreType::Efficiency),
+        ] {
+            let cpus: Option<CpuSet> = optional(parse(path.join(device).join("cpus")))?;
+            core_types.extend(cpus.into_iter().flatten().map(|cpu| (cpu, core_type)));
+        }
+
+        Ok(Self(core_types))
+    }
+
+    /// Guesses the core types of CPUs with the `hybrid_cpu` flag from their topology and caches.
+    ///
+    /// E-cores share their L2 cache with other physical cores and lack SMT,
+    /// so processors of cores sharing an L2 cache, or of single-threaded cores
+    /// on an otherwise SMT-enabled system, are considered E-cores.
+    #[must_use]
+    pub fn guess(cpu_info: &CpuInfo, topology: &Topology, caches: &Caches) -> Self {
+        if !cpu_info.cpus().any(|cpu| cpu.flags().contains(HYBRID_FLAG)) {
+            return Self::default();
+        }
+
+        let smt = topology.smt_width() > 1;
+        let mut core_types = BTreeMap::new();
+
+        for core in topology.sockets().flat_map(Socket::cores) {
+            let shared_l2 = core.processors().iter().any(|processor| {
+                caches
+                    .get(processor)
+                    .into_iter()
+                    .flatten()
+                    .filter(|cache| cache.level() == 2 && cache.kind() != CacheType::Instruction)
+                    .any(|cache| !cache.shared_cpus().is_subset(core.processors()))
+            });
+            let core_type = if shared_l2 || (smt && core.processors().len() == 1) {
+                CoreType::Efficiency
+            } else {
+                CoreType::Performance
+            };
+            core_types.extend(core.processors().iter().map(|cpu| (cpu, core_type)));
+        }
+
+        Self(core_types)
+    }
+
+    #[must_use]
+    pub fn get(&self, processor: usize) -> Option<CoreType> {
+        self.0.get(&processor).copied()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the logical processors of the given core type.
+    #[must_use]
+    pub fn cpus(&self, core_type: CoreType) -> CpuSet {
+        self.0
+            .iter()
+            .filter(|(_, other)| **other == core_type)
+            .map(|(&processor, _)| processor)
+            .collect()
+    }
+
+    /// Returns the logical processors of P-cores.
+    #[must_use]
+    pub fn performance(&self) -> CpuSet {
+        self.cpus(CoreType::Performance)
+    }
+
+    /// Returns the logical processors of E-cores.
+    #[must_use]
+    pub fn efficiency(&self) -> CpuSet {
+        self.cpus(CoreType::Efficiency)
+    }
+
+    /// Returns the CPUs of the given core type from the given CPU information.
+    pub fn select<'cpu_info>(
+        &'cpu_info self,
+        cpu_info: &'cpu_info CpuInfo,
+        core_type: CoreType,
+    ) -> impl Iterator<Item = Cpu<'cpu_info>> {
+        cpu_info
+            .cpus()
+            .filter(move |cpu| cpu.core_type(self) == Some(core_type))
+    }
+}
+
+impl Cpu<'_> {
+    /// Returns the type of the CPU's physical core, if it is part of a hybrid CPU.
+    #[must_use]
+    pub fn core_type(&self, core_types: &CoreTypes) -> Option<CoreType> {
+        self.processor()
+            .and_then(|processor| core_types.get(processor))
+    }
+}
+
+impl SysRoot {
+    /// Reads the core types from sysfs, falling back to guessing them
+    /// from the topology and caches on older kernels.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/cpuinfo` or the PMU devices could not be read
+    pub fn core_types(&self) -> Result<CoreTypes, Error> {
+        let core_types = CoreTypes::read_from(self.sys())?;
+
+        if !core_types.is_empty() {
+            return Ok(core_types);
+        }
+
+        let cpu_info = self.cpu_info()?;
+        let sysfs = self.sysfs_topology().unwrap_or_default();
+        Ok(CoreTypes::guess(
+            &cpu_info,
+            &Topology::merged(&cpu_info, &sysfs),
+            &self.caches().unwrap_or_default(),
+        ))
+    }
+}
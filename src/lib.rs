@@ -4,6 +4,64 @@ use std::fs::read_to_string;
 use std::path::Path;
 use std::str::FromStr;
 
+pub use cache::{Cache, CacheType, Caches};
+pub use cluster::{CpuCluster, CpuClusters};
+pub use controls::{CpuControls, SmtControl};
+pub use cpu_set::{CpuSet, ParseCpuSetError};
+pub use cpu_states::{CpuState, CpuStates};
+pub use cpufreq::{CpuFreq, CpuFreqs};
+pub use cpuidle::{CpuIdle, CpuIdles, IdleState};
+pub use hwmon::{SocketTemperatures, Temperature, Temperatures};
+pub use hybrid::{CoreType, CoreTypes};
+pub use llc::{LlcDomain, LlcDomains};
+pub use microarchitecture::Microarchitecture;
+pub use numa::{Numa, NumaNode};
+pub use parallelism::Parallelism;
+pub use proc_stat::{CpuTimes, ProcStat, Utilization};
+pub use rapl::{RaplSnapshot, RaplZone};
+pub use requirement::{ParseRequirementError, Requirement, Unsatisfied};
+pub use sampler::{
+    Clock, CpuMhzSource, Frequencies, FrequencySampler, FrequencySeries, FrequencySource,
+    FrequencyStats, ScalingCurFreqSource, SystemClock,
+};
+pub use sysfs_topology::{CpuTopology, SysfsTopology};
+pub use sysroot::SysRoot;
+pub use target_feature::{target_feature, X86_64Level};
+pub use throttle::{Throttle, ThrottleCounters, ThrottleSnapshot};
+pub use topology::{Core, CoreKey, Socket, Topology, TopologyError};
+pub use vendor::Vendor;
+
+mod affinity;
+#[cfg(feature = "archspec")]
+pub mod archspec;
+mod cache;
+mod cgroup;
+mod cluster;
+mod controls;
+mod cpu_set;
+mod cpu_states;
+mod cpufreq;
+mod cpuidle;
+mod hwmon;
+mod hybrid;
+mod llc;
+mod march;
+mod microarchitecture;
+mod numa;
+mod parallelism;
+mod proc_stat;
+mod rapl;
+mod requirement;
+mod sampler;
+mod signature;
+mod sysfs;
+mod sysfs_topology;
+mod sysroot;
+mod target_feature;
+mod throttle;
+mod topology;
+mod vendor;
+
 const DEFAULT_FILE: &str = "/proc/cpuinfo";
 const KIB: usize = 1024;
 const MIB: usize = 1024 * KIB;
@@ -17,7 +75,7 @@ impl CpuInfo {
     /// # Errors
     /// Returns an [`std::io::Error`] if the file could not be read
     pub fn read() -> Result<Self, std::io::Error> {
-        Self::read_from(DEFAULT_FILE)
+        SysRoot::default().cpu_info()
     }
 
     /// Reads CPU information from the given file.
@@ -33,15 +91,17 @@ impl CpuInfo {
     }
 
     pub fn cpus(&self) -> impl Iterator<Item = Cpu<'_>> {
-        self.0
-            .split("\n\n")
-            .filter(|text| !text.is_empty())
-            .map(Cpu::from_str)
+        self.blocks().map(Cpu::from_str)
     }
 
     pub fn iter(&self) -> impl Iterator<Item = Cpu<'_>> {
         self.cpus()
     }
+
+    /// Returns the text blocks of the individual CPUs.
+    fn blocks(&self) -> impl Iterator<Item = &str> {
+        self.0.split("\n\n").filter(|text| !text.trim().is_empty())
+    }
 }
 
 impl Default for CpuInfo {
@@ -252,4 +312,34 @@ impl<'cpu_info> Cpu<'cpu_info> {
     pub fn power_management(&self) -> Option<&str> {
         self.get("power management")
     }
+
+    /// Returns the ARM implementer code, e.g. `0x41` for Arm Ltd.
+    #[must_use]
+    pub fn cpu_implementer(&self) -> Option<u8> {
+        self.get("CPU implementer")
+            .and_then(|s| u8::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+    }
+
+    #[must_use]
+    pub fn cpu_architecture(&self) -> Option<u8> {
+        self.get("CPU architecture").and_then(|s| s.parse().ok())
+    }
+
+    #[must_use]
+    pub fn cpu_variant(&self) -> Option<u8> {
+        self.get("CPU variant")
+            .and_then(|s| u8::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+    }
+
+    /// Returns the ARM part number, e.g. `0xd05` for a Cortex-A55.
+    #[must_use]
+    pub fn cpu_part(&self) -> Option<u16> {
+        self.get("CPU part")
+            .and_then(|s| u16::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+    }
+
+    #[must_use]
+    pub fn cpu_revision(&self) -> Option<u8> {
+        self.get("CPU revision").and_then(|s| s.parse().ok())
+    }
 }
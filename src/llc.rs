@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+
+use crate::{CacheType, Caches, CpuSet, SysRoot, Topology};
+
+/// The groups of logical processors sharing a last-level cache.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LlcDomains(Vec<LlcDomain>);
+
+impl LlcDomains {
+    /// Determines the last-level cache domains of the logical processors of the given topology.
+    ///
+    /// Processors without cache information are considered to share a last-level cache
+    /// with the other processors of their socket.
+    #[must_use]
+    pub fn new(topology: &Topology, caches: &Caches) -> Self {
+        let mut domains = BTreeMap::new();
+
+        for socket in topology.sockets() {
+            for processor in socket.processors() {
+                let llc = caches.get(processor).and_then(|caches| {
+                    caches
+                        .iter()
+                        .filter(|cache| cache.kind() != CacheType::Instruction)
+                        .max_by_key(|cache| cache.level())
+                });
+                let (level, size, cpus) = llc.map_or_else(
+                    || (None, None, socket.processors().collect()),
+                    |cache| {
+                        (
+                            Some(cache.level()),
+                            cache.size(),
+                            cache.shared_cpus().clone(),
+                        )
+                    },
+                );
+                let domain = domains.entry(cpus).or_insert_with(|| LlcDomain {
+                    level,
+                    size,
+                    cpus: CpuSet::new(),
+                    thread_indices: BTreeMap::new(),
+                });
+                domain.cpus.insert(processor);
+                domain.thread_indices.insert(
+                    processor,
+                    topology
+                        .core_of(processor)
+                        .and_then(|core| {
+                            core.processors()
+                                .iter()
+                                .position(|other| other == processor)
+                        })
+                        .unwrap_or_default(),
+                );
+            }
+        }
+
+        Self(domains.into_values().collect())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LlcDomain> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the domain the given logical processor belongs to.
+    #[must_use]
+    pub fn domain_of(&self, processor: usize) -> Option<&LlcDomain> {
+        self.iter().find(|domain| domain.cpus.contains(processor))
+    }
+
+    /// Returns the lowest numbered logical processor of each domain.
+    #[must_use]
+    pub fn one_per_domain(&self) -> Vec<usize> {
+        self.iter()
+            .filter_map(|domain| domain.cpus.first())
+            .collect()
+    }
+
+    /// Partitions the logical processors into worker groups of at most `group_size` processors,
+    /// which never span multiple domains.
+    ///
+    /// Within a domain, groups are filled with one thread per physical core first.
+    #[must_use]
+    pub fn partition(&self, group_size: usize) -> Vec<Vec<usize>> {
+        self.iter()
+            .flat_map(|domain| {
+                domain
+                    .cores_first()
+                    .chunks(group_size.max(1))
+                    .map(<[usize]>::to_vec)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// Logical processors sharing a last-level cache.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LlcDomain {
+    level: Option<u8>,
+    size: Option<usize>,
+    cpus: CpuSet,
+    /// Index of each processor among the threads of its physical core.
+    thread_indices: BTreeMap<usize, usize>,
+}
+
+impl LlcDomain {
+    /// Returns the level of the last-level cache, if known.
+    #[must_use]
+    pub const fn level(&self) -> Option<u8> {
+        self.level
+    }
+
+    /// Returns the size of the last-level cache in bytes, if known.
+    #[must_use]
+    pub const fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// Returns the logical processors sharing the last-level cache.
+    #[must_use]
+    pub const fn cpus(&self) -> &CpuSet {
+        &self.cpus
+    }
+
+    fn cores_first(&self) -> Vec<usize> {
+        let mut cpus: Vec<_> = self.cpus.iter().collect();
+        cpus.sort_by_key(|processor| (self.thread_indices.get(processor), *processor));
+        cpus
+    }
+}
+
+impl SysRoot {
+    /// Determines the last-level cache domains from the topology and the caches in sysfs.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/cpuinfo` could not be read
+    pub fn llc_domains(&self) -> Result<LlcDomains, Error> {
+        Ok(LlcDomains::new(
+            &self.topology()?,
+            &self.caches().unwrap_or_default(),
+        ))
+    }
+}
@@ -0,0 +1,135 @@
+use crate::signature::{Signature, ANY};
+use crate::{Cpu, Vendor, X86_64Level};
+
+/// Known GCC / Clang `-march` targets, the first match wins.
+const MARCHES: [(Signature, &str, X86_64Level); 97] = {
+    use Vendor::{Amd, Centaur, Hygon, Zhaoxin};
+    use X86_64Level::{V1, V2, V3, V4};
+
+    [
+        // Intel Core
+        (Signature::intel(0x0f), "core2", V1),
+        (Signature::intel(0x17), "core2", V1),
+        (Signature::intel(0x1d), "core2", V1),
+        (Signature::intel(0x1a), "nehalem", V2),
+        (Signature::intel(0x1e), "nehalem", V2),
+        (Signature::intel(0x1f), "nehalem", V2),
+        (Signature::intel(0x2e), "nehalem", V2),
+        (Signature::intel(0x25), "westmere", V2),
+        (Signature::intel(0x2c), "westmere", V2),
+        (Signature::intel(0x2f), "westmere", V2),
+        (Signature::intel(0x2a), "sandybridge", V2),
+        (Signature::intel(0x2d), "sandybridge", V2),
+        (Signature::intel(0x3a), "ivybridge", V2),
+        (Signature::intel(0x3e), "ivybridge", V2),
+        (Signature::intel(0x3c), "haswell", V3),
+        (Signature::intel(0x3f), "haswell", V3),
+        (Signature::intel(0x45), "haswell", V3),
+        (Signature::intel(0x46), "haswell", V3),
+        (Signature::intel(0x3d), "broadwell", V3),
+        (Signature::intel(0x47), "broadwell", V3),
+        (Signature::intel(0x4f), "broadwell", V3),
+        (Signature::intel(0x56), "broadwell", V3),
+        (Signature::intel(0x4e), "skylake", V3),
+        (Signature::intel(0x5e), "skylake", V3),
+        (Signature::intel(0x8e), "skylake", V3),
+        (Signature::intel(0x9e), "skylake", V3),
+        (Signature::intel(0xa5), "skylake", V3),
+        (Signature::intel(0xa6), "skylake", V3),
+        (Signature::intel(0x55).steppings(10..=11), "cooperlake", V4),
+        (Signature::intel(0x55).steppings(5..=7), "cascadelake", V4),
+        (Signature::intel(0x55), "skylake-avx512", V4),
+        (Signature::intel(0x66), "cannonlake", V4),
+        (Signature::intel(0x6a), "icelake-server", V4),
+        (Signature::intel(0x6c), "icelake-server", V4),
+        (Signature::intel(0x7d), "icelake-client", V4),
+        (Signature::intel(0x7e), "icelake-client", V4),
+        (Signature::intel(0x9d), "icelake-client", V4),
+        (Signature::intel(0x8c), "tigerlake", V4),
+        (Signature::intel(0x8d), "tigerlake", V4),
+        (Signature::intel(0xa7), "rocketlake", V4),
+        (Signature::intel(0x97), "alderlake", V3),
+        (Signature::intel(0x9a), "alderlake", V3),
+        (Signature::intel(0xbe), "alderlake", V3),
+        (Signature::intel(0xb7), "raptorlake", V3),
+        (Signature::intel(0xba), "raptorlake", V3),
+        (Signature::intel(0xbf), "raptorlake", V3),
+        (Signature::intel(0xaa), "meteorlake", V3),
+        (Signature::intel(0xac), "meteorlake", V3),
+        (Signature::intel(0xb5), "arrowlake", V3),
+        (Signature::intel(0xc5), "arrowlake", V3),
+        (Signature::intel(0xc6), "arrowlake-s", V3),
+        (Signature::intel(0xbd), "lunarlake", V3),
+        (Signature::intel(0x8f), "sapphirerapids", V4),
+        (Signature::intel(0xcf), "emeraldrapids", V4),
+        (Signature::intel(0xad), "graniterapids", V4),
+        (Signature::intel(0xae), "graniterapids", V4),
+        // Intel Atom
+        (Signature::intel(0x1c), "bonnell", V1),
+        (Signature::intel(0x26), "bonnell", V1),
+        (Signature::intel(0x37), "silvermont", V2),
+        (Signature::intel(0x4a), "silvermont", V2),
+        (Signature::intel(0x4d), "silvermont", V2),
+        (Signature::intel(0x5a), "silvermont", V2),
+        (Signature::intel(0x5d), "silvermont", V2),
+        (Signature::intel(0x5c), "goldmont", V2),
+        (Signature::intel(0x5f), "goldmont", V2),
+        (Signature::intel(0x7a), "goldmont-plus", V2),
+        (Signature::intel(0x86), "tremont", V2),
+        (Signature::intel(0x96), "tremont", V2),
+        (Signature::intel(0x9c), "tremont", V2),
+        (Signature::intel(0xaf), "sierraforest", V3),
+        (Signature::intel(0xb6), "grandridge", V3),
+        (Signature::intel(0xdd), "clearwaterforest", V3),
+        // Intel Xeon Phi
+        (Signature::intel(0x57), "knl", V3),
+        (Signature::intel(0x85), "knm", V3),
+        // AMD
+        (Signature::new(Amd, 0x10, ANY), "amdfam10", V1),
+        (Signature::new(Amd, 0x14, ANY), "btver1", V1),
+        (Signature::new(Amd, 0x15, 0x02..=0x02), "bdver2", V2),
+        (Signature::new(Amd, 0x15, 0x10..=0x1f), "bdver2", V2),
+        (Signature::new(Amd, 0x15, 0x30..=0x3f), "bdver3", V2),
+        (Signature::new(Amd, 0x15, 0x60..=0x7f), "bdver4", V3),
+        (Signature::new(Amd, 0x15, ANY), "bdver1", V2),
+        (Signature::new(Amd, 0x16, ANY), "btver2", V2),
+        (Signature::new(Amd, 0x17, 0x30..=0x3f), "znver2", V3),
+        (Signature::new(Amd, 0x17, 0x47..=0x47), "znver2", V3),
+        (Signature::new(Amd, 0x17, 0x60..=0x7f), "znver2", V3),
+        (Signature::new(Amd, 0x17, 0x84..=0x87), "znver2", V3),
+        (Signature::new(Amd, 0x17, 0x90..=0xaf), "znver2", V3),
+        (Signature::new(Amd, 0x17, ANY), "znver1", V3),
+        (Signature::new(Amd, 0x19, 0x10..=0x1f), "znver4", V4),
+        (Signature::new(Amd, 0x19, 0x60..=0xaf), "znver4", V4),
+        (Signature::new(Amd, 0x19, ANY), "znver3", V3),
+        (Signature::new(Amd, 0x1a, ANY), "znver5", V4),
+        // Hygon
+        (Signature::new(Hygon, 0x18, ANY), "znver1", V3),
+        // Zhaoxin
+        (Signature::new(Zhaoxin, 0x07, 0x3b..=0x3b), "lujiazui", V2),
+        (Signature::new(Centaur, 0x07, 0x3b..=0x3b), "lujiazui", V2),
+        (Signature::new(Zhaoxin, 0x07, 0x5b..=0x5b), "yongfeng", V3),
+        (Signature::new(Centaur, 0x07, 0x5b..=0x5b), "yongfeng", V3),
+    ]
+};
+
+impl Cpu<'_> {
+    /// Returns the most specific GCC / Clang `-march` value for the CPU.
+    ///
+    /// Falls back to the generic x86-64 micro-architecture level,
+    /// if the CPU model is unknown or does not support all features of its nominal target,
+    /// e.g. because they are masked by a hypervisor.
+    /// Returns `None` on non-x86-64 CPUs.
+    #[must_use]
+    pub fn march(&self) -> Option<&'static str> {
+        let level = self.x86_64_level()?;
+
+        Some(
+            MARCHES
+                .iter()
+                .find(|(signature, _, _)| signature.matches(self))
+                .filter(|(_, _, required)| *required <= level)
+                .map_or_else(|| level.name(), |(_, name, _)| name),
+        )
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt::{Display, Formatter};
+
+use crate::signature::{Signature, ANY};
+use crate::{Cpu, Vendor};
+
+/// A vendor's microarchitecture and the codename of the respective product generation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Microarchitecture {
+    vendor: Vendor,
+    name: &'static str,
+    codename: &'static str,
+}
+
+impl Microarchitecture {
+    const fn new(vendor: Vendor, name: &'static str, codename: &'static str) -> Self {
+        Self {
+            vendor,
+            name,
+            codename,
+        }
+    }
+
+    #[must_use]
+    pub const fn vendor(&self) -> Vendor {
+        self.vendor
+    }
+
+    /// Returns the microarchitecture's name, e.g. `Alder Lake` or `Zen 3`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the product generation's codename, e.g. `Alder Lake-S` or `Vermeer`.
+    #[must_use]
+    pub const fn codename(&self) -> &'static str {
+        self.codename
+    }
+}
+
+impl Display for Microarchitecture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.name == self.codename {
+            write!(f, "{} {}", self.vendor, self.name)
+        } else {
+            write!(f, "{} {} ({})", self.vendor, self.name, self.codename)
+        }
+    }
+}
+
+/// Known microarchitectures, the first match wins.
+const MICROARCHITECTURES: [(Signature, &str, &str); 134] = {
+    use Vendor::{Amd, Centaur, Hygon, Intel, Zhaoxin};
+
+    [
+        // Intel Core
+        (Signature::intel(0x0f), "Core", "Merom"),
+        (Signature::intel(0x17), "Penryn", "Penryn"),
+        (Signature::intel(0x1d), "Penryn", "Dunnington"),
+        (Signature::intel(0x1a), "Nehalem", "Bloomfield"),
+        (Signature::intel(0x1e), "Nehalem", "Lynnfield"),
+        (Signature::intel(0x1f), "Nehalem", "Havendale"),
+        (Signature::intel(0x2e), "Nehalem", "Nehalem-EX"),
+        (Signature::intel(0x25), "Westmere", "Arrandale"),
+        (Signature::intel(0x2c), "Westmere", "Westmere-EP"),
+        (Signature::intel(0x2f), "Westmere", "Westmere-EX"),
+        (Signature::intel(0x2a), "Sandy Bridge", "Sandy Bridge"),
+        (Signature::intel(0x2d), "Sandy Bridge", "Sandy Bridge-E"),
+        (Signature::intel(0x3a), "Ivy Bridge", "Ivy Bridge"),
+        (Signature::intel(0x3e), "Ivy Bridge", "Ivy Bridge-E"),
+        (Signature::intel(0x3c), "Haswell", "Haswell-S"),
+        (Signature::intel(0x3f), "Haswell", "Haswell-E"),
+        (Signature::intel(0x45), "Haswell", "Haswell-ULT"),
+        (Signature::intel(0x46), "Haswell", "Crystal Well"),
+        (Signature::intel(0x3d), "Broadwell", "Broadwell-U"),
+        (Signature::intel(0x47), "Broadwell", "Broadwell-H"),
+        (Signature::intel(0x4f), "Broadwell", "Broadwell-E"),
+        (Signature::intel(0x56), "Broadwell", "Broadwell-DE"),
+        (Signature::intel(0x4e), "Skylake", "Skylake-U"),
+        (Signature::intel(0x5e), "Skylake", "Skylake-S"),
+        (
+            Signature::intel(0x55).steppings(10..=11),
+            "Cooper Lake",
+            "Cooper Lake-SP",
+        ),
+        (
+            Signature::intel(0x55).steppings(5..=7),
+            "Cascade Lake",
+            "Cascade Lake-SP",
+        ),
+        (Signature::intel(0x55), "Skylake", "Skylake-SP"),
+        (
+            Signature::intel(0x8e).steppings(0..=10),
+            "Kaby Lake",
+            "Kaby Lake-U",
+        ),
+        (
+            Signature::intel(0x8e).steppings(11..=11),
+            "Coffee Lake",
+            "Whiskey Lake-U",
+        ),
+        (Signature::intel(0x8e), "Comet Lake", "Comet Lake-U"),
+        (
+            Signature::intel(0x9e).steppings(0..=9),
+            "Kaby Lake",
+            "Kaby Lake-S",
+        ),
+        (Signature::intel(0x9e), "Coffee Lake", "Coffee Lake-S"),
+        (Signature::intel(0xa5), "Comet Lake", "Comet Lake-S"),
+        (Signature::intel(0xa6), "Comet Lake", "Comet Lake-U"),
+        (Signature::intel(0x66), "Cannon Lake", "Cannon Lake-U"),
+        (Signature::intel(0x7d), "Ice Lake", "Ice Lake-Y"),
+        (Signature::intel(0x7e), "Ice Lake", "Ice Lake-U"),
+        (Signature::intel(0x9d), "Ice Lake", "Ice Lake-NNPI"),
+        (Signature::intel(0x6a), "Ice Lake", "Ice Lake-SP"),
+        (Signature::intel(0x6c), "Ice Lake", "Ice Lake-D"),
+        (Signature::intel(0x8c), "Tiger Lake", "Tiger Lake-UP3"),
+        (Signature::intel(0x8d), "Tiger Lake", "Tiger Lake-H"),
+        (Signature::intel(0xa7), "Rocket Lake", "Rocket Lake-S"),
+        (Signature::intel(0x97), "Alder Lake", "Alder Lake-S"),
+        (Signature::intel(0x9a), "Alder Lake", "Alder Lake-P"),
+        (Signature::intel(0xbe), "Alder Lake", "Alder Lake-N"),
+        (Signature::intel(0xb7), "Raptor Lake", "Raptor Lake-S"),
+        (Signature::intel(0xba), "Raptor Lake", "Raptor Lake-P"),
+        (Signature::intel(0xbf), "Raptor Lake", "Raptor Lake-S"),
+        (Signature::intel(0xaa), "Meteor Lake", "Meteor Lake-H"),
+        (Signature::intel(0xac), "Meteor Lake", "Meteor Lake-S"),
+        (Signature::intel(0xc6), "Arrow Lake", "Arrow Lake-S"),
+        (Signature::intel(0xc5), "Arrow Lake", "Arrow Lake-H"),
+        (Signature::intel(0xb5), "Arrow Lake", "Arrow Lake-U"),
+        (Signature::intel(0xbd), "Lunar Lake", "Lunar Lake-M"),
+        (Signature::intel(0xcc), "Panther Lake", "Panther Lake"),
+        (
+            Signature::intel(0x8f),
+            "Sapphire Rapids",
+            "Sapphire Rapids-SP",
+        ),
+        (
+            Signature::intel(0xcf),
+            "Emerald Rapids",
+            "Emerald Rapids-SP",
+        ),
+        (Signature::intel(0xad), "Granite Rapids", "Granite Rapids-X"),
+        (Signature::intel(0xae), "Granite Rapids", "Granite Rapids-D"),
+        // Intel Atom
+        (Signature::intel(0x1c), "Bonnell", "Diamondville"),
+        (Signature::intel(0x26), "Bonnell", "Lincroft"),
+        (Signature::intel(0x27), "Saltwell", "Penwell"),
+        (Signature::intel(0x35), "Saltwell", "Cloverview"),
+        (Signature::intel(0x36), "Saltwell", "Cedarview"),
+        (Signature::intel(0x37), "Silvermont", "Bay Trail"),
+        (Signature::intel(0x4a), "Silvermont", "Merrifield"),
+        (Signature::intel(0x4d), "Silvermont", "Avoton"),
+        (Signature::intel(0x5a), "Silvermont", "Moorefield"),
+        (Signature::intel(0x4c), "Airmont", "Cherry Trail"),
+        (Signature::intel(0x5d), "Airmont", "SoFIA"),
+        (Signature::intel(0x5c), "Goldmont", "Apollo Lake"),
+        (Signature::intel(0x5f), "Goldmont", "Denverton"),
+        (Signature::intel(0x7a), "Goldmont Plus", "Gemini Lake"),
+        (Signature::intel(0x86), "Tremont", "Snow Ridge"),
+        (Signature::intel(0x96), "Tremont", "Elkhart Lake"),
+        (Signature::intel(0x9c), "Tremont", "Jasper Lake"),
+        (Signature::intel(0xaf), "Crestmont", "Sierra Forest"),
+        (Signature::intel(0xb6), "Crestmont", "Grand Ridge"),
+        (Signature::intel(0xdd), "Darkmont", "Clearwater Forest"),
+        // Intel Xeon Phi
+        (Signature::intel(0x57), "Knights Landing", "Knights Landing"),
+        (Signature::intel(0x85), "Knights Mill", "Knights Mill"),
+        // Intel NetBurst
+        (Signature::new(Intel, 0x0f, ANY), "NetBurst", "NetBurst"),
+        // AMD
+        (Signature::new(Amd, 0x0f, ANY), "K8", "Hammer"),
+        (Signature::new(Amd, 0x10, 0x02..=0x02), "K10", "Barcelona"),
+        (Signature::new(Amd, 0x10, 0x04..=0x04), "K10", "Deneb"),
+        (Signature::new(Amd, 0x10, 0x08..=0x08), "K10", "Istanbul"),
+        (Signature::new(Amd, 0x10, 0x09..=0x09), "K10", "Magny-Cours"),
+        (Signature::new(Amd, 0x10, 0x0a..=0x0a), "K10", "Thuban"),
+        (Signature::new(Amd, 0x10, ANY), "K10", "K10"),
+        (Signature::new(Amd, 0x12, ANY), "K10", "Llano"),
+        (Signature::new(Amd, 0x14, ANY), "Bobcat", "Zacate"),
+        (
+            Signature::new(Amd, 0x15, 0x02..=0x02),
+            "Piledriver",
+            "Vishera",
+        ),
+        (
+            Signature::new(Amd, 0x15, 0x00..=0x0f),
+            "Bulldozer",
+            "Zambezi",
+        ),
+        (
+            Signature::new(Amd, 0x15, 0x10..=0x1f),
+            "Piledriver",
+            "Trinity",
+        ),
+        (
+            Signature::new(Amd, 0x15, 0x30..=0x3f),
+            "Steamroller",
+            "Kaveri",
+        ),
+        (
+            Signature::new(Amd, 0x15, 0x60..=0x6f),
+            "Excavator",
+            "Carrizo",
+        ),
+        (
+            Signature::new(Amd, 0x15, 0x70..=0x7f),
+            "Excavator",
+            "Stoney Ridge",
+        ),
+        (Signature::new(Amd, 0x16, 0x00..=0x0f), "Jaguar", "Kabini"),
+        (Signature::new(Amd, 0x16, 0x30..=0x3f), "Puma", "Beema"),
+        (Signature::new(Amd, 0x17, 0x01..=0x01), "Zen", "Naples"),
+        (
+            Signature::new(Amd, 0x17, 0x08..=0x08),
+            "Zen+",
+            "Pinnacle Ridge",
+        ),
+        (Signature::new(Amd, 0x17, 0x11..=0x11), "Zen", "Raven Ridge"),
+        (Signature::new(Amd, 0x17, 0x18..=0x18), "Zen+", "Picasso"),
+        (Signature::new(Amd, 0x17, 0x20..=0x20), "Zen", "Dali"),
+        (Signature::new(Amd, 0x17, 0x31..=0x31), "Zen 2", "Rome"),
+        (Signature::new(Amd, 0x17, 0x60..=0x60), "Zen 2", "Renoir"),
+        (Signature::new(Amd, 0x17, 0x68..=0x68), "Zen 2", "Lucienne"),
+        (Signature::new(Amd, 0x17, 0x71..=0x71), "Zen 2", "Matisse"),
+        (Signature::new(Amd, 0x17, 0x90..=0x91), "Zen 2", "Van Gogh"),
+        (Signature::new(Amd, 0x17, 0xa0..=0xaf), "Zen 2", "Mendocino"),
+        (Signature::new(Amd, 0x19, 0x00..=0x01), "Zen 3", "Milan"),
+        (Signature::new(Amd, 0x19, 0x08..=0x08), "Zen 3", "Chagall"),
+        (Signature::new(Amd, 0x19, 0x10..=0x1f), "Zen 4", "Genoa"),
+        (Signature::new(Amd, 0x19, 0x20..=0x2f), "Zen 3", "Vermeer"),
+        (
+            Signature::new(Amd, 0x19, 0x40..=0x4f),
+            "Zen 3+",
+            "Rembrandt",
+        ),
+        (Signature::new(Amd, 0x19, 0x50..=0x5f), "Zen 3", "Cezanne"),
+        (Signature::new(Amd, 0x19, 0x60..=0x6f), "Zen 4", "Raphael"),
+        (Signature::new(Amd, 0x19, 0x70..=0x7b), "Zen 4", "Phoenix"),
+        (
+            Signature::new(Amd, 0x19, 0x7c..=0x7f),
+            "Zen 4",
+            "Hawk Point",
+        ),
+        (Signature::new(Amd, 0x19, 0xa0..=0xaf), "Zen 4c", "Bergamo"),
+        (Signature::new(Amd, 0x1a, 0x00..=0x1f), "Zen 5", "Turin"),
+        (
+            Signature::new(Amd, 0x1a, 0x20..=0x2f),
+            "Zen 5",
+            "Strix Point",
+        ),
+        (
+            Signature::new(Amd, 0x1a, 0x40..=0x4f),
+            "Zen 5",
+            "Granite Ridge",
+        ),
+        (
+            Signature::new(Amd, 0x1a, 0x70..=0x7f),
+            "Zen 5",
+            "Strix Halo",
+        ),
+        // Hygon
+        (Signature::new(Hygon, 0x18, ANY), "Zen", "Dhyana"),
+        // Zhaoxin
+        (
+            Signature::new(Centaur, 0x06, 0x0f..=0x0f),
+            "Isaiah",
+            "VIA Nano",
+        ),
+        (
+            Signature::new(Centaur, 0x06, 0x19..=0x19),
+            "ZhangJiang",
+            "ZX-C",
+        ),
+        (
+            Signature::new(Zhaoxin, 0x07, 0x1b..=0x1b),
+            "WuDaoKou",
+            "KX-5000",
+        ),
+        (
+            Signature::new(Centaur, 0x07, 0x1b..=0x1b),
+            "WuDaoKou",
+            "KX-5000",
+        ),
+        (
+            Signature::new(Zhaoxin, 0x07, 0x3b..=0x3b),
+            "LuJiaZui",
+            "KX-6000",
+        ),
+        (
+            Signature::new(Centaur, 0x07, 0x3b..=0x3b),
+            "LuJiaZui",
+            "KX-6000",
+        ),
+        (
+            Signature::new(Zhaoxin, 0x07, 0x5b..=0x5b),
+            "YongFeng",
+            "KX-7000 / KH-40000",
+        ),
+        (
+            Signature::new(Centaur, 0x07, 0x5b..=0x5b),
+            "YongFeng",
+            "KX-7000 / KH-40000",
+        ),
+    ]
+};
+
+impl Cpu<'_> {
+    /// Returns the CPU's microarchitecture as identified by its vendor, family, model and stepping.
+    #[must_use]
+    pub fn microarchitecture(&self) -> Option<Microarchitecture> {
+        let vendor = self.vendor()?;
+        MICROARCHITECTURES
+            .iter()
+            .find(|(signature, _, _)| signature.matches(self))
+            .map(|(_, name, codename)| Microarchitecture::new(vendor, name, codename))
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use crate::sysfs::{indexed_dirs, optional, parse, read};
+use crate::{Cpu, CpuInfo, CpuSet, SysRoot, KIB};
+
+const NODE_DIR: &str = "devices/system/node";
+
+/// The NUMA nodes of a system as reported by `/sys/devices/system/node`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Numa(BTreeMap<usize, NumaNode>);
+
+impl Numa {
+    /// Reads the NUMA nodes from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the NUMA nodes could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().numa()
+    }
+
+    /// Reads the NUMA nodes from the given sysfs root.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the NUMA nodes could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let dirs = indexed_dirs(sysfs_root.as_ref().join(NODE_DIR), "node")?;
+        let ids: Vec<_> = dirs.keys().copied().collect();
+        dirs.into_iter()
+            .map(|(id, path)| NumaNode::read_from(id, &path, &ids).map(|node| (id, node)))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &NumaNode> {
+        self.0.values()
+    }
+
+    #[must_use]
+    pub fn node(&self, id: usize) -> Option<&NumaNode> {
+        self.0.get(&id)
+    }
+
+    /// Returns the NUMA node the given logical processor belongs to.
+    #[must_use]
+    pub fn node_of(&self, processor: usize) -> Option<&NumaNode> {
+        self.nodes().find(|node| node.cpus.contains(processor))
+    }
+
+    /// Returns the relative distance between the given nodes.
+    #[must_use]
+    pub fn distance(&self, from: usize, to: usize) -> Option<u32> {
+        self.node(from)
+            .and_then(|node| node.distances.get(&to))
+            .copied()
+    }
+
+    /// Returns the distances between all nodes, ordered by node ID.
+    #[must_use]
+    pub fn distance_matrix(&self) -> Vec<Vec<u32>> {
+        self.nodes()
+            .map(|node| node.distances.values().copied().collect())
+            .collect()
+    }
+}
+
+/// A NUMA node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NumaNode {
+    id: usize,
+    cpus: CpuSet,
+    distances: BTreeMap<usize, u32>,
+    mem_total: Option<usize>,
+    mem_free: Option<usize>,
+}
+
+impl NumaNode {
+    fn read_from(id: usize, path: &Path, ids: &[usize]) -> Result<Self, Error> {
+        let cpus = optional(parse(path.join("cpulist")))?.unwrap_or_default();
+        let distances = optional(read(path.join("distance")))?
+            .unwrap_or_default()
+            .split_whitespace()
+            .zip(ids)
+            .map(|(distance, &node)| {
+                distance
+                    .parse()
+                    .map(|distance| (node, distance))
+                    .map_err(|_| {
+                        Error::new(
+                            ErrorKind::InvalidData,
+                            format!("invalid distance of node {id}: {distance}"),
+                        )
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        let meminfo = optional(read(path.join("meminfo")))?.unwrap_or_default();
+
+        Ok(Self {
+            id,
+            cpus,
+            distances,
+            mem_total: meminfo_value(&meminfo, "MemTotal"),
+            mem_free: meminfo_value(&meminfo, "MemFree"),
+        })
+    }
+
+    #[must_use]
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the logical processors of the node.
+    #[must_use]
+    pub const fn cpus(&self) -> &CpuSet {
+        &self.cpus
+    }
+
+    /// Returns the relative distances to all nodes by node ID.
+    #[must_use]
+    pub const fn distances(&self) -> &BTreeMap<usize, u32> {
+        &self.distances
+    }
+
+    /// Returns the node's total memory in bytes.
+    #[must_use]
+    pub const fn mem_total(&self) -> Option<usize> {
+        self.mem_total
+    }
+
+    /// Returns the node's free memory in bytes.
+    #[must_use]
+    pub const fn mem_free(&self) -> Option<usize> {
+        self.mem_free
+    }
+
+    /// Returns the CPUs of the node from the given CPU information.
+    pub fn select<'cpu_info>(
+        &'cpu_info self,
+        cpu_info: &'cpu_info CpuInfo,
+    ) -> impl Iterator<Item = Cpu<'cpu_info>> {
+        cpu_info.cpus().filter(|cpu| {
+            cpu.processor()
+                .is_some_and(|processor| self.cpus.contains(processor))
+        })
+    }
+}
+
+impl SysRoot {
+    /// Reads the NUMA nodes from `/sys/devices/system/node`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the NUMA nodes could not be read
+    pub fn numa(&self) -> Result<Numa, Error> {
+        Numa::read_from(self.sys())
+    }
+}
+
+/// Returns a value in bytes from a node's `meminfo`, e.g. `Node 0 MemTotal: 32768000 kB`.
+fn meminfo_value(meminfo: &str, key: &str) -> Option<usize> {
+    meminfo.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+
+        if name.split_whitespace().last()? != key {
+            return None;
+        }
+
+        match value.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [value] => value.parse().ok(),
+            [value, "kB"] => value.parse::<usize>().ok().map(|value| value * KIB),
+            _ => None,
+        }
+    })
+}
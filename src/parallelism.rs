@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+
+use crate::cgroup::{self, Hierarchy};
+use crate::sysfs::{optional, parse, read};
+use crate::{CpuSet, Numa, NumaNode, Socket, SysRoot, Topology};
+
+/// Recommended thread-pool sizes for the current process,
+/// taking the CPU affinity, the cgroup CPU quota, SMT and NUMA into account.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Parallelism {
+    quota: Option<f64>,
+    threads: usize,
+    cores: usize,
+    node_threads: BTreeMap<usize, usize>,
+    node_cores: BTreeMap<usize, usize>,
+}
+
+impl Parallelism {
+    /// Determines the parallelism from the topology, the allowed processors and the CPU quota
+    /// in CPUs, i.e. the cgroup's quota divided by its period.
+    ///
+    /// Without NUMA information, all processors are considered to be on node `0`.
+    #[must_use]
+    pub fn new(topology: &Topology, allowed: &CpuSet, numa: &Numa, quota: Option<f64>) -> Self {
+        let limit = quota.map_or(usize::MAX, quota_limit);
+        let mut node_threads = BTreeMap::new();
+        let mut node_cores = BTreeMap::new();
+        let (mut threads, mut cores) = (0, 0);
+
+        for core in topology.sockets().flat_map(Socket::cores) {
+            let mut nodes = BTreeMap::new();
+
+            for processor in core.processors().intersection(allowed) {
+                let node = numa.node_of(processor).map_or(0, NumaNode::id);
+                *nodes.entry(node).or_insert(0) += 1;
+                threads += 1;
+            }
+
+            if !nodes.is_empty() {
+                cores += 1;
+            }
+
+            for (node, count) in nodes {
+                *node_threads.entry(node).or_insert(0) += count;
+                *node_cores.entry(node).or_insert(0) += 1;
+            }
+        }
+
+        let cap = |counts: BTreeMap<usize, usize>| {
+            counts
+                .into_iter()
+                .map(|(node, count)| (node, count.min(limit)))
+                .collect()
+        };
+
+        Self {
+            quota,
+            threads: threads.min(limit),
+            cores: cores.min(limit),
+            node_threads: cap(node_threads),
+            node_cores: cap(node_cores),
+        }
+    }
+
+    /// Returns the CPU quota in CPUs, if any.
+    #[must_use]
+    pub const fn quota(&self) -> Option<f64> {
+        self.quota
+    }
+
+    /// Returns the amount of usable logical processors, limited by the CPU quota.
+    #[must_use]
+    pub const fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Returns the amount of physical cores with at least one usable logical processor,
+    /// limited by the CPU quota.
+    #[must_use]
+    pub const fn cores(&self) -> usize {
+        self.cores
+    }
+
+    /// Returns the amount of usable logical processors per NUMA node.
+    #[must_use]
+    pub const fn node_threads(&self) -> &BTreeMap<usize, usize> {
+        &self.node_threads
+    }
+
+    /// Returns the amount of physical cores with usable logical processors per NUMA node.
+    #[must_use]
+    pub const fn node_cores(&self) -> &BTreeMap<usize, usize> {
+        &self.node_cores
+    }
+}
+
+impl SysRoot {
+    /// Reads the CPU quota of the current process's cgroup in CPUs.
+    ///
+    /// Reads `cpu.max` on cgroup v2 and `cpu.cfs_quota_us` / `cpu.cfs_period_us` on cgroup v1.
+    /// The lowest quota of the cgroup and its ancestors applies.
+    /// Returns `None` if the CPU usage is not limited.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/self/cgroup` or the cgroup files could not be read
+    pub fn cpu_quota(&self) -> Result<Option<f64>, Error> {
+        let mut quota: Option<f64> = None;
+
+        for (hierarchy, dir) in cgroup::dirs(self, "cpu")? {
+            let limit = match hierarchy {
+                // A quota of -1 means unlimited.
+                Hierarchy::V1 => match optional(parse::<i64>(dir.join("cpu.cfs_quota_us")))?
+                    .and_then(|quota| u64::try_from(quota).ok())
+                {
+                    Some(quota) => Some((quota, parse(dir.join("cpu.cfs_period_us"))?)),
+                    None => None,
+                },
+                Hierarchy::V2 => optional(read(dir.join("cpu.max")))?
+                    .map(|max| parse_cpu_max(&max))
+                    .transpose()?
+                    .flatten(),
+            };
+
+            if let Some((limit, period)) = limit.filter(|&(_, period)| period > 0) {
+                // The quota and period are in microseconds, which stay far below 2^52.
+                #[allow(clippy::cast_precision_loss)]
+                let limit = limit as f64 / period as f64;
+                quota = Some(quota.map_or(limit, |quota| quota.min(limit)));
+            }
+        }
+
+        Ok(quota)
+    }
+
+    /// Determines the parallelism of the current process.
+    ///
+    /// Missing affinity, NUMA or cgroup information is considered to not restrict the process.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/cpuinfo` could not be read
+    pub fn parallelism(&self) -> Result<Parallelism, Error> {
+        let topology = self.topology()?;
+        let allowed = self
+            .allowed_cpus()
+            .unwrap_or_else(|_| topology.sockets().flat_map(Socket::processors).collect());
+
+        Ok(Parallelism::new(
+            &topology,
+            &allowed,
+            &self.numa().unwrap_or_default(),
+            self.cpu_quota().ok().flatten(),
+        ))
+    }
+}
+
+/// Parses cgroup v2's `cpu.max`, which is either `max <period>` or `<quota> <period>`.
+fn parse_cpu_max(max: &str) -> Result<Option<(u64, u64)>, Error> {
+    let invalid = || Error::new(ErrorKind::InvalidData, format!("invalid cpu.max: {max}"));
+    let mut fields = max.split_whitespace();
+    let quota = fields.next().ok_or_else(invalid)?;
+    let period = fields
+        .next()
+        .map_or(Ok(100_000), str::parse)
+        .map_err(|_| invalid())?;
+
+    if quota == "max" {
+        return Ok(None);
+    }
+
+    quota
+        .parse()
+        .map(|quota| Some((quota, period)))
+        .map_err(|_| invalid())
+}
+
+/// Rounds a quota of e.g. 1.5 CPUs up to two threads, but to at least one.
+// The cast saturates, mapping negative quotas to zero.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn quota_limit(quota: f64) -> usize {
+    (quota.ceil() as usize).max(1)
+}
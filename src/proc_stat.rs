@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use crate::{CoreKey, Numa, SysRoot, Topology};
+
+/// A snapshot of the time each logical processor spent in the various states,
+/// as reported by the `cpuN` lines of `/proc/stat`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProcStat(BTreeMap<usize, CpuTimes>);
+
+impl ProcStat {
+    /// Reads `/proc/stat`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the file could not be read or parsed
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().proc_stat()
+    }
+
+    /// Reads the given file in the format of `/proc/stat`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the file could not be read or parsed
+    pub fn read_from(filename: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut times = BTreeMap::new();
+
+        for line in read_to_string(filename)?.lines() {
+            let mut fields = line.split_whitespace();
+
+            let Some(processor) = fields
+                .next()
+                .and_then(|name| name.strip_prefix("cpu"))
+                .filter(|processor| !processor.is_empty())
+            else {
+                continue;
+            };
+
+            let processor = processor.parse().map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid CPU: cpu{processor}"),
+                )
+            })?;
+            let values = fields
+                .map(|value| {
+                    value.parse().map_err(|_| {
+                        Error::new(
+                            ErrorKind::InvalidData,
+                            format!("invalid time of cpu{processor}: {value}"),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<u64>, _>>()?;
+            let value = |index: usize| values.get(index).copied().unwrap_or_default();
+
+            times.insert(
+                processor,
+                CpuTimes {
+                    user: value(0),
+                    nice: value(1),
+                    system: value(2),
+                    idle: value(3),
+                    iowait: value(4),
+                    irq: value(5),
+                    softirq: value(6),
+                    steal: value(7),
+                },
+            );
+        }
+
+        Ok(Self(times))
+    }
+
+    #[must_use]
+    pub fn get(&self, processor: usize) -> Option<&CpuTimes> {
+        self.0.get(&processor)
+    }
+
+    /// Returns the logical processors and their times.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &CpuTimes)> {
+        self.0.iter().map(|(&processor, times)| (processor, times))
+    }
+
+    /// Returns the utilization since the given earlier snapshot.
+    ///
+    /// Processors missing from the earlier snapshot, e.g. because they were offline, are skipped.
+    #[must_use]
+    pub fn utilization_since(&self, earlier: &Self) -> Utilization {
+        Utilization(
+            self.iter()
+                .filter_map(|(processor, times)| {
+                    earlier
+                        .get(processor)
+                        .map(|earlier| (processor, times.delta(earlier)))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The time a logical processor spent in the various states in clock ticks (`USER_HZ`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CpuTimes {
+    user: u64,
+    nice: u64,
+    system: u64,
+    idle: u64,
+    iowait: u64,
+    irq: u64,
+    softirq: u64,
+    steal: u64,
+}
+
+impl CpuTimes {
+    #[must_use]
+    pub const fn user(&self) -> u64 {
+        self.user
+    }
+
+    #[must_use]
+    pub const fn nice(&self) -> u64 {
+        self.nice
+    }
+
+    #[must_use]
+    pub const fn system(&self) -> u64 {
+        self.system
+    }
+
+    #[must_use]
+    pub const fn idle(&self) -> u64 {
+        self.idle
+    }
+
+    #[must_use]
+    pub const fn iowait(&self) -> u64 {
+        self.iowait
+    }
+
+    #[must_use]
+    pub const fn irq(&self) -> u64 {
+        self.irq
+    }
+
+    #[must_use]
+    pub const fn softirq(&self) -> u64 {
+        self.softirq
+    }
+
+    /// Returns the time stolen by the hypervisor for other virtual machines.
+    #[must_use]
+    pub const fn steal(&self) -> u64 {
+        self.steal
+    }
+
+    /// Returns the time spent neither idle nor waiting for I/O.
+    #[must_use]
+    pub const fn busy(&self) -> u64 {
+        self.user + self.nice + self.system + self.irq + self.softirq + self.steal
+    }
+
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.busy() + self.idle + self.iowait
+    }
+
+    /// Returns the busy fraction of the total time between `0` and `1`,
+    /// or `None` if no time elapsed.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Clock ticks stay far below 2^52.
+    pub fn utilization(&self) -> Option<f64> {
+        (self.total() > 0).then(|| self.busy() as f64 / self.total() as f64)
+    }
+
+    const fn delta(&self, earlier: &Self) -> Self {
+        Self {
+            user: self.user.saturating_sub(earlier.user),
+            nice: self.nice.saturating_sub(earlier.nice),
+            system: self.system.saturating_sub(earlier.system),
+            idle: self.idle.saturating_sub(earlier.idle),
+            iowait: self.iowait.saturating_sub(earlier.iowait),
+            irq: self.irq.saturating_sub(earlier.irq),
+            softirq: self.softirq.saturating_sub(earlier.softirq),
+            steal: self.steal.saturating_sub(earlier.steal),
+        }
+    }
+
+    const fn add(&mut self, other: &Self) {
+        self.user += other.user;
+        self.nice += other.nice;
+        self.system += other.system;
+        self.idle += other.idle;
+        self.iowait += other.iowait;
+        self.irq += other.irq;
+        self.softirq += other.softirq;
+        self.steal += other.steal;
+    }
+}
+
+/// The time each logical processor spent in the various states between two snapshots.
+///
+/// The aggregates sum up the times of their logical processors,
+/// so their utilization is the average utilization of those processors.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Utilization(BTreeMap<usize, CpuTimes>);
+
+impl Utilization {
+    #[must_use]
+    pub fn get(&self, processor: usize) -> Option<&CpuTimes> {
+        self.0.get(&processor)
+    }
+
+    /// Returns the logical processors and their times.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &CpuTimes)> {
+        self.0.iter().map(|(&processor, times)| (processor, times))
+    }
+
+    /// Returns the utilization of each logical processor between `0` and `1`.
+    #[must_use]
+    pub fn processors(&self) -> BTreeMap<usize, f64> {
+        self.iter()
+            .filter_map(|(processor, times)| Some((processor, times.utilization()?)))
+            .collect()
+    }
+
+    /// Returns the summed times by physical core.
+    #[must_use]
+    pub fn cores(&self, topology: &Topology) -> BTreeMap<CoreKey, CpuTimes> {
+        topology
+            .cores()
+            .filter_map(|(key, core)| self.sum(core.processors().iter()).map(|times| (key, times)))
+            .collect()
+    }
+
+    /// Returns the summed times by socket.
+    #[must_use]
+    pub fn sockets(&self, topology: &Topology) -> BTreeMap<usize, CpuTimes> {
+        topology
+            .sockets()
+            .filter_map(|socket| {
+                self.sum(socket.processors())
+                    .map(|times| (socket.id(), times))
+            })
+            .collect()
+    }
+
+    /// Returns the summed times by NUMA node.
+    #[must_use]
+    pub fn nodes(&self, numa: &Numa) -> BTreeMap<usize, CpuTimes> {
+        numa.nodes()
+            .filter_map(|node| self.sum(node.cpus().iter()).map(|times| (node.id(), times)))
+            .collect()
+    }
+
+    /// Sums up the times of the given processors, returning `None` if none of them were sampled.
+    fn sum(&self, processors: impl Iterator<Item = usize>) -> Option<CpuTimes> {
+        processors
+            .filter_map(|processor| self.get(processor))
+            .fold(None, |sum, times| {
+                let mut sum = sum.unwrap_or_default();
+                sum.add(times);
+                Some(sum)
+            })
+    }
+}
+
+impl SysRoot {
+    /// Reads `/proc/stat`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the file could not be read or parsed
+    pub fn proc_stat(&self) -> Result<ProcStat, Error> {
+        ProcStat::read_from(self.proc().join("stat"))
+    }
+}
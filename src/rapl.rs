@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+use std::fs::read_dir;
+use std::io::Error;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::sysfs::{parse, read};
+use crate::SysRoot;
+
+const POWERCAP_DIR: &str = "class/powercap";
+const ZONE_PREFIX: &str = "intel-rapl:";
+
+/// A snapshot of the RAPL energy counters as reported by `/sys/class/powercap/intel-rapl:*`,
+/// which the kernel also uses for AMD CPUs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RaplSnapshot {
+    time: Instant,
+    zones: Vec<RaplZone>,
+}
+
+impl RaplSnapshot {
+    /// Reads the energy counters from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the energy counters could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().rapl()
+    }
+
+    /// Reads the energy counters from the given sysfs root.
+    ///
+    /// The kernel names the zones `package-X` or, on systems with multiple dies per package,
+    /// `package-X-die-Y` after the `physical id` `X` of the socket and its die `Y`.
+    /// Subzones belong to the socket of their parent zone.
+    ///
+    /// Note that `energy_uj` is only readable by root on recent kernels.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the energy counters could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut paths = BTreeMap::new();
+
+        for entry in read_dir(sysfs_root.as_ref().join(POWERCAP_DIR))? {
+            let entry = entry?;
+
+            if let Some(id) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix(ZONE_PREFIX))
+                .and_then(|id| {
+                    id.split(':')
+                        .map(str::parse)
+                        .collect::<Result<Vec<usize>, _>>()
+                        .ok()
+                })
+            {
+                paths.insert(id, entry.path());
+            }
+        }
+
+        let mut zones = Vec::new();
+        let mut packages = BTreeMap::new();
+
+        for (id, path) in paths {
+            let name = read(path.join("name"))?;
+            let (socket, die) = match id.as_slice() {
+                [zone] => {
+                    let package = package(&name);
+                    packages.insert(*zone, package);
+                    package
+                }
+                [parent, ..] => packages.get(parent).copied().unwrap_or_default(),
+                [] => (None, None),
+            };
+
+            zones.push(RaplZone {
+                id: id
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(":"),
+                name,
+                socket,
+                die,
+                energy_uj: parse(path.join("energy_uj"))?,
+                max_energy_range_uj: parse(path.join("max_energy_range_uj"))?,
+            });
+        }
+
+        Ok(Self {
+            time: Instant::now(),
+            zones,
+        })
+    }
+
+    /// Returns the time at which the snapshot was taken.
+    #[must_use]
+    pub const fn time(&self) -> Instant {
+        self.time
+    }
+
+    /// Returns all zones, e.g. `package-0` and its `core`, `uncore` and `dram` subzones.
+    #[must_use]
+    pub fn zones(&self) -> &[RaplZone] {
+        &self.zones
+    }
+
+    /// Returns the zone with the given id, e.g. `0:1` for `intel-rapl:0:1`.
+    #[must_use]
+    pub fn zone(&self, id: &str) -> Option<&RaplZone> {
+        self.zones.iter().find(|zone| zone.id == id)
+    }
+
+    /// Returns the `package-X` and `package-X-die-Y` zones by socket.
+    #[must_use]
+    pub fn packages(&self) -> BTreeMap<usize, Vec<&RaplZone>> {
+        let mut packages: BTreeMap<usize, Vec<&RaplZone>> = BTreeMap::new();
+
+        for zone in self.zones.iter().filter(|zone| zone.is_package()) {
+            if let Some(socket) = zone.socket {
+                packages.entry(socket).or_default().push(zone);
+            }
+        }
+
+        packages
+    }
+
+    /// Returns the energy consumed by each socket since the given earlier snapshot in microjoules,
+    /// summed up over its dies.
+    ///
+    /// Zones missing from the earlier snapshot are skipped.
+    #[must_use]
+    pub fn energy_since(&self, earlier: &Self) -> BTreeMap<usize, u64> {
+        self.packages()
+            .into_iter()
+            .filter_map(|(socket, zones)| {
+                zones
+                    .into_iter()
+                    .filter_map(|zone| {
+                        earlier
+                            .zones
+                            .iter()
+                            .find(|earlier| earlier.name == zone.name)
+                            .map(|earlier| zone.energy_since(earlier))
+                    })
+                    .reduce(u64::saturating_add)
+                    .map(|energy_uj| (socket, energy_uj))
+            })
+            .collect()
+    }
+
+    /// Returns the average power of each socket since the given earlier snapshot in watts.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // The counters wrap around far below 2^52.
+    pub fn power_since(&self, earlier: &Self) -> BTreeMap<usize, f64> {
+        let seconds = self.time.duration_since(earlier.time).as_secs_f64();
+
+        if seconds == 0.0 {
+            return BTreeMap::new();
+        }
+
+        self.energy_since(earlier)
+            .into_iter()
+            .map(|(socket, energy_uj)| (socket, energy_uj as f64 / 1_000_000.0 / seconds))
+            .collect()
+    }
+}
+
+/// A RAPL power zone and its energy counter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RaplZone {
+    id: String,
+    name: String,
+    socket: Option<usize>,
+    die: Option<usize>,
+    energy_uj: u64,
+    max_energy_range_uj: u64,
+}
+
+impl RaplZone {
+    /// Returns the id of the zone, e.g. `0:1` for `intel-rapl:0:1`.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the name of the zone, e.g. `package-0`, `core`, `dram` or `psys`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the socket of the zone, which is `None` for platform-wide zones like `psys`.
+    #[must_use]
+    pub const fn socket(&self) -> Option<usize> {
+        self.socket
+    }
+
+    /// Returns the die of the zone on systems with multiple dies per package.
+    #[must_use]
+    pub const fn die(&self) -> Option<usize> {
+        self.die
+    }
+
+    #[must_use]
+    pub fn is_package(&self) -> bool {
+        self.name.starts_with("package-")
+    }
+
+    /// Returns the energy counter in microjoules.
+    #[must_use]
+    pub const fn energy_uj(&self) -> u64 {
+        self.energy_uj
+    }
+
+    /// Returns the value at which the energy counter wraps around.
+    #[must_use]
+    pub const fn max_energy_range_uj(&self) -> u64 {
+        self.max_energy_range_uj
+    }
+
+    /// Returns the energy consumed since the given earlier reading of the same zone in microjoules,
+    /// assuming that the counter wrapped around at most once.
+    #[must_use]
+    pub const fn energy_since(&self, earlier: &Self) -> u64 {
+        if self.energy_uj >= earlier.energy_uj {
+            self.energy_uj - earlier.energy_uj
+        } else {
+            self.max_energy_range_uj
+                .saturating_sub(earlier.energy_uj)
+                .saturating_add(self.energy_uj)
+        }
+    }
+}
+
+impl SysRoot {
+    /// Reads the RAPL energy counters from `/sys/class/powercap`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the energy counters could not be read
+    pub fn rapl(&self) -> Result<RaplSnapshot, Error> {
+        RaplSnapshot::read_from(self.sys())
+    }
+}
+
+/// Parses the socket and die of a `package-X` or `package-X-die-Y` zone.
+fn package(name: &str) -> (Option<usize>, Option<usize>) {
+    let Some(package) = name.strip_prefix("package-") else {
+        return (None, None);
+    };
+
+    match package.split_once("-die-") {
+        Some((socket, die)) => (socket.parse().ok(), die.parse().ok()),
+        None => (package.parse().ok(), None),
+    }
+}
@@ -0,0 +1,411 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::{Cpu, CpuInfo};
+
+const AND: &str = "&&";
+const OR: &str = "||";
+const NOT: char = '!';
+const BUG: &str = "bug";
+const VMX: &str = "vmx";
+/// The maximum nesting depth of negations and parentheses.
+const MAX_DEPTH: usize = 64;
+
+/// A boolean expression over a CPU's `flags`, `vmx flags` and `bugs`.
+///
+/// Terms are plain flag names, optionally followed by `vmx` to refer to a VMX flag
+/// or by `bug` to refer to a bug.
+/// Terms can be combined with `&&`, `||`, `!` and parentheses, e.g.
+/// `avx2 && (avx512f || avx_vnni) && !tsx_async_abort bug`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Requirement {
+    Flag(String),
+    VmxFlag(String),
+    Bug(String),
+    Not(Box<Self>),
+    And(Vec<Self>),
+    Or(Vec<Self>),
+}
+
+impl Requirement {
+    /// Returns `true` if the given CPU satisfies the requirement.
+    #[must_use]
+    pub fn is_satisfied_by(&self, cpu: &Cpu<'_>) -> bool {
+        self.failures(&Features::from(cpu)).is_empty()
+    }
+
+    /// Checks the requirement against the given CPU.
+    /// # Errors
+    /// Returns an [`Unsatisfied`] error naming the failing terms if the CPU does not satisfy the requirement
+    pub fn check(&self, cpu: &Cpu<'_>) -> Result<(), Unsatisfied> {
+        let terms = self.failures(&Features::from(cpu));
+
+        if terms.is_empty() {
+            Ok(())
+        } else {
+            Err(Unsatisfied {
+                processor: cpu.processor(),
+                terms,
+            })
+        }
+    }
+
+    /// Checks the requirement against all CPUs.
+    /// # Errors
+    /// Returns an [`Unsatisfied`] error for the first CPU that does not satisfy the requirement
+    pub fn check_all(&self, cpu_info: &CpuInfo) -> Result<(), Unsatisfied> {
+        cpu_info.cpus().try_for_each(|cpu| self.check(&cpu))
+    }
+
+    fn failures(&self, features: &Features<'_>) -> Vec<String> {
+        match self {
+            Self::Flag(name) | Self::VmxFlag(name) | Self::Bug(name) => {
+                if features.contains(self, name) {
+                    Vec::new()
+                } else {
+                    vec![self.to_string()]
+                }
+            }
+            Self::Not(requirement) => {
+                if requirement.failures(features).is_empty() {
+                    vec![self.to_string()]
+                } else {
+                    Vec::new()
+                }
+            }
+            Self::And(requirements) => requirements
+                .iter()
+                .flat_map(|requirement| requirement.failures(features))
+                .collect(),
+            Self::Or(requirements) => {
+                let failures: Vec<_> = requirements
+                    .iter()
+                    .map(|requirement| requirement.failures(features))
+                    .collect();
+
+                if failures.iter().any(Vec::is_empty) {
+                    Vec::new()
+                } else {
+                    failures.into_iter().flatten().collect()
+                }
+            }
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::And(_) | Self::Or(_) => write!(f, "({self})"),
+            _ => write!(f, "{self}"),
+        }
+    }
+}
+
+impl Display for Requirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Flag(name) => write!(f, "{name}"),
+            Self::VmxFlag(name) => write!(f, "{name} {VMX}"),
+            Self::Bug(name) => write!(f, "{name} {BUG}"),
+            Self::Not(requirement) => {
+                write!(f, "{NOT}")?;
+                requirement.fmt_operand(f)
+            }
+            Self::And(requirements) | Self::Or(requirements) => {
+                let operator = if matches!(self, Self::And(_)) {
+                    AND
+                } else {
+                    OR
+                };
+
+                for (index, requirement) in requirements.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " {operator} ")?;
+                    }
+
+                    requirement.fmt_operand(f)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for Requirement {
+    type Err = ParseRequirementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(tokenize(s)?).parse()
+    }
+}
+
+/// Error returned when a CPU does not satisfy a [`Requirement`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unsatisfied {
+    processor: Option<usize>,
+    terms: Vec<String>,
+}
+
+impl Unsatisfied {
+    /// Returns the processor that did not satisfy the requirement.
+    #[must_use]
+    pub const fn processor(&self) -> Option<usize> {
+        self.processor
+    }
+
+    /// Returns the terms that were not satisfied.
+    #[must_use]
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+}
+
+impl Display for Unsatisfied {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.processor {
+            Some(processor) => write!(f, "processor {processor} does not satisfy: ")?,
+            None => write!(f, "CPU does not satisfy: ")?,
+        }
+
+        write!(f, "{}", self.terms.join(", "))
+    }
+}
+
+impl Error for Unsatisfied {}
+
+/// Error returned when a [`Requirement`] could not be parsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseRequirementError {
+    InvalidCharacter(char, usize),
+    UnexpectedToken(String, usize),
+    UnexpectedEnd,
+    /// Negations and parentheses are nested deeper than the limit at the given position.
+    TooDeep(usize),
+}
+
+impl Display for ParseRequirementError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCharacter(chr, position) => {
+                write!(f, "invalid character '{chr}' at position {position}")
+            }
+            Self::UnexpectedToken(token, position) => {
+                write!(f, "unexpected token '{token}' at position {position}")
+            }
+            Self::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            Self::TooDeep(position) => {
+                write!(f, "expression nested too deeply at position {position}")
+            }
+        }
+    }
+}
+
+impl Error for ParseRequirementError {}
+
+struct Features<'cpu> {
+    flags: HashSet<&'cpu str>,
+    vmx_flags: HashSet<&'cpu str>,
+    bugs: HashSet<&'cpu str>,
+}
+
+impl Features<'_> {
+    fn contains(&self, term: &Requirement, name: &str) -> bool {
+        match term {
+            Requirement::VmxFlag(_) => self.vmx_flags.contains(name),
+            Requirement::Bug(_) => self.bugs.contains(name),
+            _ => self.flags.contains(name),
+        }
+    }
+}
+
+impl<'cpu> From<&'cpu Cpu<'_>> for Features<'cpu> {
+    fn from(cpu: &'cpu Cpu<'_>) -> Self {
+        Self {
+            flags: cpu.flags(),
+            vmx_flags: cpu.vmx_flags(),
+            bugs: cpu.bugs(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Token<'expr> {
+    Identifier(&'expr str),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Identifier(name) => write!(f, "{name}"),
+            Self::And => write!(f, "{AND}"),
+            Self::Or => write!(f, "{OR}"),
+            Self::Not => write!(f, "{NOT}"),
+            Self::Open => write!(f, "("),
+            Self::Close => write!(f, ")"),
+        }
+    }
+}
+
+fn tokenize(expr: &str) -> Result<Vec<(usize, Token<'_>)>, ParseRequirementError> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.char_indices().peekable();
+
+    while let Some((position, chr)) = chars.next() {
+        let token = match chr {
+            '(' => Token::Open,
+            ')' => Token::Close,
+            NOT => Token::Not,
+            '&' | '|' => {
+                if chars.next_if(|&(_, next)| next == chr).is_none() {
+                    return Err(ParseRequirementError::InvalidCharacter(chr, position));
+                }
+
+                if chr == '&' {
+                    Token::And
+                } else {
+                    Token::Or
+                }
+            }
+            chr if chr.is_whitespace() => continue,
+            chr if is_identifier(chr) => {
+                let mut end = position + chr.len_utf8();
+
+                while let Some((index, next)) = chars.next_if(|&(_, next)| is_identifier(next)) {
+                    end = index + next.len_utf8();
+                }
+
+                Token::Identifier(&expr[position..end])
+            }
+            chr => return Err(ParseRequirementError::InvalidCharacter(chr, position)),
+        };
+
+        tokens.push((position, token));
+    }
+
+    Ok(tokens)
+}
+
+const fn is_identifier(chr: char) -> bool {
+    chr.is_ascii_alphanumeric() || matches!(chr, '_' | '.' | '-')
+}
+
+struct Parser<'expr> {
+    tokens: std::iter::Peekable<std::vec::IntoIter<(usize, Token<'expr>)>>,
+    depth: usize,
+}
+
+impl<'expr> Parser<'expr> {
+    fn new(tokens: Vec<(usize, Token<'expr>)>) -> Self {
+        Self {
+            tokens: tokens.into_iter().peekable(),
+            depth: 0,
+        }
+    }
+
+    fn parse(mut self) -> Result<Requirement, ParseRequirementError> {
+        let requirement = self.parse_or()?;
+
+        match self.tokens.next() {
+            Some((position, token)) => Err(ParseRequirementError::UnexpectedToken(
+                token.to_string(),
+                position,
+            )),
+            None => Ok(requirement),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Requirement, ParseRequirementError> {
+        let mut requirements = vec![self.parse_and()?];
+
+        while self
+            .tokens
+            .next_if(|(_, token)| *token == Token::Or)
+            .is_some()
+        {
+            requirements.push(self.parse_and()?);
+        }
+
+        Ok(if requirements.len() == 1 {
+            requirements.remove(0)
+        } else {
+            Requirement::Or(requirements)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Requirement, ParseRequirementError> {
+        let mut requirements = vec![self.parse_unary()?];
+
+        while self
+            .tokens
+            .next_if(|(_, token)| *token == Token::And)
+            .is_some()
+        {
+            requirements.push(self.parse_unary()?);
+        }
+
+        Ok(if requirements.len() == 1 {
+            requirements.remove(0)
+        } else {
+            Requirement::And(requirements)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Requirement, ParseRequirementError> {
+        match self.tokens.next() {
+            Some((position, Token::Not)) => self.nested(position, |parser| {
+                Ok(Requirement::Not(Box::new(parser.parse_unary()?)))
+            }),
+            Some((position, Token::Open)) => self.nested(position, |parser| {
+                let requirement = parser.parse_or()?;
+
+                match parser.tokens.next() {
+                    Some((_, Token::Close)) => Ok(requirement),
+                    Some((position, token)) => Err(ParseRequirementError::UnexpectedToken(
+                        token.to_string(),
+                        position,
+                    )),
+                    None => Err(ParseRequirementError::UnexpectedEnd),
+                }
+            }),
+            Some((_, Token::Identifier(name))) => Ok(self.parse_term(name)),
+            Some((position, token)) => Err(ParseRequirementError::UnexpectedToken(
+                token.to_string(),
+                position,
+            )),
+            None => Err(ParseRequirementError::UnexpectedEnd),
+        }
+    }
+
+    /// Parses a negated or parenthesized expression, limiting the recursion depth.
+    fn nested(
+        &mut self,
+        position: usize,
+        parse: impl FnOnce(&mut Self) -> Result<Requirement, ParseRequirementError>,
+    ) -> Result<Requirement, ParseRequirementError> {
+        if self.depth == MAX_DEPTH {
+            return Err(ParseRequirementError::TooDeep(position));
+        }
+
+        self.depth += 1;
+        let requirement = parse(self);
+        self.depth -= 1;
+        requirement
+    }
+
+    fn parse_term(&mut self, name: &str) -> Requirement {
+        match self.tokens.next_if(|(_, token)| {
+            matches!(token, Token::Identifier(qualifier) if *qualifier == BUG || *qualifier == VMX)
+        }) {
+            Some((_, Token::Identifier(BUG))) => Requirement::Bug(name.to_string()),
+            Some(_) => Requirement::VmxFlag(name.to_string()),
+            None => Requirement::Flag(name.to_string()),
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use crate::sysfs::{cpu_dirs, optional, parse, CPU_DIR};
+use crate::SysRoot;
+
+/// Frequencies in MHz by logical processor.
+pub type Frequencies = BTreeMap<usize, f64>;
+
+/// A clock driving a [`FrequencySampler`].
+pub trait Clock {
+    /// Returns the time elapsed since an arbitrary, but fixed starting point.
+    fn now(&mut self) -> Duration;
+
+    /// Waits for the given duration.
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// The system's monotonic clock.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SystemClock(Instant);
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self(Instant::now())
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&mut self) -> Duration {
+        self.0.elapsed()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        sleep(duration);
+    }
+}
+
+/// A source of the current frequency of each logical processor.
+pub trait FrequencySource {
+    /// Returns the current frequency of each logical processor.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the frequencies could not be read
+    fn frequencies(&mut self) -> Result<Frequencies, Error>;
+}
+
+impl<F> FrequencySource for F
+where
+    F: FnMut() -> Result<Frequencies, Error>,
+{
+    fn frequencies(&mut self) -> Result<Frequencies, Error> {
+        self()
+    }
+}
+
+/// Reads the frequencies from the `cpu MHz` of `/proc/cpuinfo`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CpuMhzSource(SysRoot);
+
+impl CpuMhzSource {
+    #[must_use]
+    pub const fn new(sysroot: SysRoot) -> Self {
+        Self(sysroot)
+    }
+}
+
+impl FrequencySource for CpuMhzSource {
+    fn frequencies(&mut self) -> Result<Frequencies, Error> {
+        Ok(self
+            .0
+            .cpu_info()?
+            .cpus()
+            .filter_map(|cpu| Some((cpu.processor()?, f64::from(cpu.cpu_mhz()?))))
+            .collect())
+    }
+}
+
+/// Reads the frequencies from cpufreq's `scaling_cur_freq`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScalingCurFreqSource(SysRoot);
+
+impl ScalingCurFreqSource {
+    #[must_use]
+    pub const fn new(sysroot: SysRoot) -> Self {
+        Self(sysroot)
+    }
+}
+
+impl FrequencySource for ScalingCurFreqSource {
+    fn frequencies(&mut self) -> Result<Frequencies, Error> {
+        let mut frequencies = BTreeMap::new();
+
+        for (processor, path) in cpu_dirs(self.0.sys().join(CPU_DIR))? {
+            if let Some(khz) = optional(parse::<u32>(path.join("cpufreq/scaling_cur_freq")))? {
+                frequencies.insert(processor, f64::from(khz) / 1000.0);
+            }
+        }
+
+        Ok(frequencies)
+    }
+}
+
+/// Periodically samples the frequency of each logical processor.
+#[derive(Clone, Debug)]
+pub struct FrequencySampler<C, S> {
+    clock: C,
+    source: S,
+    interval: Duration,
+}
+
+impl<C, S> FrequencySampler<C, S>
+where
+    C: Clock,
+    S: FrequencySource,
+{
+    #[must_use]
+    pub const fn new(clock: C, source: S, interval: Duration) -> Self {
+        Self {
+            clock,
+            source,
+            interval,
+        }
+    }
+
+    /// Takes the given amount of samples, one per interval.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the frequencies could not be read
+    pub fn sample(&mut self, count: usize) -> Result<FrequencySeries, Error> {
+        let mut series = FrequencySeries::default();
+
+        for index in 0..count {
+            if index > 0 {
+                self.clock.sleep(self.interval);
+            }
+
+            series.push(self.clock.now(), self.source.frequencies()?);
+        }
+
+        Ok(series)
+    }
+
+    /// Takes samples, one per interval, until the given duration has elapsed.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the frequencies could not be read
+    pub fn sample_for(&mut self, duration: Duration) -> Result<FrequencySeries, Error> {
+        let start = self.clock.now();
+        let mut series = FrequencySeries::default();
+
+        loop {
+            series.push(self.clock.now(), self.source.frequencies()?);
+
+            if self.clock.now().saturating_sub(start) + self.interval > duration {
+                return Ok(series);
+            }
+
+            self.clock.sleep(self.interval);
+        }
+    }
+}
+
+/// A time series of frequency samples.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrequencySeries(Vec<(Duration, Frequencies)>);
+
+impl FrequencySeries {
+    /// Adds a sample taken at the given time.
+    pub fn push(&mut self, time: Duration, frequencies: Frequencies) {
+        self.0.push((time, frequencies));
+    }
+
+    /// Returns the samples and the times at which they were taken.
+    pub fn iter(&self) -> impl Iterator<Item = (Duration, &Frequencies)> {
+        self.0
+            .iter()
+            .map(|(time, frequencies)| (*time, frequencies))
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the frequencies of the given logical processor over time.
+    #[must_use]
+    pub fn processor(&self, processor: usize) -> Vec<(Duration, f64)> {
+        self.iter()
+            .filter_map(|(time, frequencies)| {
+                frequencies
+                    .get(&processor)
+                    .map(|&frequency| (time, frequency))
+            })
+            .collect()
+    }
+
+    /// Returns the statistics of the given logical processor.
+    #[must_use]
+    pub fn stats(&self, processor: usize) -> Option<FrequencyStats> {
+        FrequencyStats::new(
+            self.processor(processor)
+                .into_iter()
+                .map(|(_, frequency)| frequency)
+                .collect(),
+        )
+    }
+
+    /// Returns the statistics of all sampled logical processors.
+    #[must_use]
+    pub fn all_stats(&self) -> BTreeMap<usize, FrequencyStats> {
+        let mut frequencies: BTreeMap<usize, Vec<f64>> = BTreeMap::new();
+
+        for (_, sample) in self.iter() {
+            for (&processor, &frequency) in sample {
+                frequencies.entry(processor).or_default().push(frequency);
+            }
+        }
+
+        frequencies
+            .into_iter()
+            .filter_map(|(processor, frequencies)| {
+                FrequencyStats::new(frequencies).map(|stats| (processor, stats))
+            })
+            .collect()
+    }
+}
+
+/// Statistics of the sampled frequencies of a logical processor in MHz.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrequencyStats {
+    sorted: Vec<f64>,
+    mean: f64,
+}
+
+impl FrequencyStats {
+    #[allow(clippy::cast_precision_loss)] // The amount of samples stays far below 2^52.
+    fn new(mut frequencies: Vec<f64>) -> Option<Self> {
+        if frequencies.is_empty() {
+            return None;
+        }
+
+        frequencies.sort_by(f64::total_cmp);
+        let mean = frequencies.iter().sum::<f64>() / frequencies.len() as f64;
+        Some(Self {
+            sorted: frequencies,
+            mean,
+        })
+    }
+
+    /// Returns the amount of samples.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    #[must_use]
+    pub fn min(&self) -> f64 {
+        self.sorted[0]
+    }
+
+    #[must_use]
+    pub fn max(&self) -> f64 {
+        self.sorted[self.sorted.len() - 1]
+    }
+
+    #[must_use]
+    pub const fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the given percentile between `0` and `100` using the nearest-rank method.
+    #[must_use]
+    // The rank lies between `0` and the amount of samples.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn percentile(&self, percentile: f64) -> f64 {
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * self.sorted.len() as f64).ceil();
+        self.sorted[(rank as usize).clamp(1, self.sorted.len()) - 1]
+    }
+}
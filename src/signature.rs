@@ -0,0 +1,42 @@
+use std::ops::RangeInclusive;
+
+use crate::{Cpu, Vendor};
+
+pub const ANY: RangeInclusive<usize> = 0..=usize::MAX;
+
+/// Identifies a range of CPUs by vendor, family, model and stepping.
+pub struct Signature {
+    vendor: Vendor,
+    family: u8,
+    models: RangeInclusive<usize>,
+    steppings: RangeInclusive<usize>,
+}
+
+impl Signature {
+    pub const fn new(vendor: Vendor, family: u8, models: RangeInclusive<usize>) -> Self {
+        Self {
+            vendor,
+            family,
+            models,
+            steppings: ANY,
+        }
+    }
+
+    pub const fn intel(model: usize) -> Self {
+        Self::new(Vendor::Intel, 6, model..=model)
+    }
+
+    pub const fn steppings(mut self, steppings: RangeInclusive<usize>) -> Self {
+        self.steppings = steppings;
+        self
+    }
+
+    pub fn matches(&self, cpu: &Cpu<'_>) -> bool {
+        cpu.vendor() == Some(self.vendor)
+            && cpu.cpu_family() == Some(self.family)
+            && cpu
+                .model()
+                .is_some_and(|model| self.models.contains(&model))
+            && self.steppings.contains(&cpu.stepping().unwrap_or_default())
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::{read_dir, read_to_string};
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const CPU_DIR: &str = "devices/system/cpu";
+
+/// Reads the trimmed content of the given file.
+pub fn read(path: impl AsRef<Path>) -> Result<String, Error> {
+    read_to_string(path).map(|content| content.trim().to_string())
+}
+
+/// Reads and parses the trimmed content of the given file.
+pub fn parse<T>(path: impl AsRef<Path>) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let path = path.as_ref();
+    read(path)?.parse().map_err(|error| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("{}: {error}", path.display()),
+        )
+    })
+}
+
+/// Maps a missing file to `None`.
+pub fn optional<T>(result: Result<T, Error>) -> Result<Option<T>, Error> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the `cpuN` directories below the given directory by processor.
+pub fn cpu_dirs(path: impl AsRef<Path>) -> Result<BTreeMap<usize, PathBuf>, Error> {
+    indexed_dirs(path, "cpu")
+}
+
+/// Returns the directories below the given directory named `<prefix>N` by their index `N`.
+pub fn indexed_dirs(
+    path: impl AsRef<Path>,
+    prefix: &str,
+) -> Result<BTreeMap<usize, PathBuf>, Error> {
+    let mut dirs = BTreeMap::new();
+
+    for entry in read_dir(path)? {
+        let entry = entry?;
+
+        if let Some(index) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix(prefix))
+            .and_then(|index| index.parse().ok())
+        {
+            dirs.insert(index, entry.path());
+        }
+    }
+
+    Ok(dirs)
+}
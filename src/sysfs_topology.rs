@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::path::Path;
+
+use crate::sysfs::{cpu_dirs, optional, parse, CPU_DIR};
+use crate::{CpuSet, SysRoot};
+
+/// Topology of the logical processors as reported by
+/// `/sys/devices/system/cpu/cpuN/topology`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SysfsTopology(BTreeMap<usize, CpuTopology>);
+
+impl SysfsTopology {
+    /// Reads the CPU topology from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the topology could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().sysfs_topology()
+    }
+
+    /// Reads the CPU topology from the given sysfs root.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the topology could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut cpus = BTreeMap::new();
+
+        for (processor, path) in cpu_dirs(sysfs_root.as_ref().join(CPU_DIR))? {
+            let path = path.join("topology");
+
+            // Offline CPUs have no topology.
+            if path.is_dir() {
+                cpus.insert(processor, CpuTopology::read_from(processor, &path)?);
+            }
+        }
+
+        Ok(Self(cpus))
+    }
+
+    #[must_use]
+    pub fn get(&self, processor: usize) -> Option<&CpuTopology> {
+        self.0.get(&processor)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CpuTopology> {
+        self.0.values()
+    }
+}
+
+/// Topology of a single logical processor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CpuTopology {
+    processor: usize,
+    physical_package_id: Option<usize>,
+    die_id: Option<usize>,
+    cluster_id: Option<usize>,
+    core_id: Option<usize>,
+    thread_siblings: CpuSet,
+    core_cpus: CpuSet,
+}
+
+impl CpuTopology {
+    fn read_from(processor: usize, path: &Path) -> Result<Self, Error> {
+        Ok(Self {
+            processor,
+            physical_package_id: id(path, "physical_package_id")?,
+            die_id: id(path, "die_id")?,
+            cluster_id: id(path, "cluster_id")?,
+            core_id: id(path, "core_id")?,
+            thread_siblings: cpus(path, "thread_siblings_list")?,
+            core_cpus: cpus(path, "core_cpus_list")?,
+        })
+    }
+
+    #[must_use]
+    pub const fn processor(&self) -> usize {
+        self.processor
+    }
+
+    #[must_use]
+    pub const fn physical_package_id(&self) -> Option<usize> {
+        self.physical_package_id
+    }
+
+    #[must_use]
+    pub const fn die_id(&self) -> Option<usize> {
+        self.die_id
+    }
+
+    #[must_use]
+    pub const fn cluster_id(&self) -> Option<usize> {
+        self.cluster_id
+    }
+
+    #[must_use]
+    pub const fn core_id(&self) -> Option<usize> {
+        self.core_id
+    }
+
+    /// Returns the logical processors sharing the physical core, including this one.
+    #[must_use]
+    pub const fn thread_siblings(&self) -> &CpuSet {
+        &self.thread_siblings
+    }
+
+    /// Returns the logical processors of the physical core, including this one.
+    ///
+    /// This is the successor of `thread_siblings_list` on newer kernels.
+    #[must_use]
+    pub const fn core_cpus(&self) -> &CpuSet {
+        &self.core_cpus
+    }
+}
+
+/// Reads an ID, which is missing on older kernels and `-1` if unknown to the kernel.
+fn id(path: &Path, name: &str) -> Result<Option<usize>, Error> {
+    optional(parse::<isize>(path.join(name))).map(|id| id.and_then(|id| id.try_into().ok()))
+}
+
+fn cpus(path: &Path, name: &str) -> Result<CpuSet, Error> {
+    optional(parse(path.join(name))).map(Option::unwrap_or_default)
+}
@@ -0,0 +1,86 @@
+use std::io::Error;
+use std::path::{Path, PathBuf};
+
+use crate::sysfs::read;
+use crate::{CpuInfo, SysfsTopology, Topology};
+
+const DEFAULT_ROOT: &str = "/";
+
+/// The root directory below which `/proc` and `/sys` are read.
+///
+/// Defaults to `/` for the live system, but may point to any directory tree
+/// with the same layout, such as an extracted sosreport or a test fixture.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SysRoot(PathBuf);
+
+impl SysRoot {
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self(root.into())
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Returns the path of the `proc` filesystem.
+    #[must_use]
+    pub fn proc(&self) -> PathBuf {
+        self.0.join("proc")
+    }
+
+    /// Returns the path of the `sys` filesystem.
+    #[must_use]
+    pub fn sys(&self) -> PathBuf {
+        self.0.join("sys")
+    }
+
+    /// Reads `/proc/cpuinfo`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the file could not be read
+    pub fn cpu_info(&self) -> Result<CpuInfo, Error> {
+        CpuInfo::read_from(self.proc().join("cpuinfo"))
+    }
+
+    /// Reads the kernel command line from `/proc/cmdline`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the file could not be read
+    pub fn cmdline(&self) -> Result<String, Error> {
+        read(self.proc().join("cmdline"))
+    }
+
+    /// Reads the CPU topology from `/sys/devices/system/cpu`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the topology could not be read
+    pub fn sysfs_topology(&self) -> Result<SysfsTopology, Error> {
+        SysfsTopology::read_from(self.sys())
+    }
+
+    /// Reads the CPU topology from `/proc/cpuinfo`, merged with sysfs if available.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if `/proc/cpuinfo` could not be read
+    pub fn topology(&self) -> Result<Topology, Error> {
+        let cpu_info = self.cpu_info()?;
+        let sysfs = self.sysfs_topology().unwrap_or_default();
+        Ok(Topology::merged(&cpu_info, &sysfs))
+    }
+}
+
+impl Default for SysRoot {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROOT)
+    }
+}
+
+impl From<&Path> for SysRoot {
+    fn from(path: &Path) -> Self {
+        Self::new(path)
+    }
+}
+
+impl From<PathBuf> for SysRoot {
+    fn from(path: PathBuf) -> Self {
+        Self(path)
+    }
+}
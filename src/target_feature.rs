@@ -0,0 +1,161 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::Cpu;
+
+/// Kernel CPU flags and their corresponding `rustc` target features.
+const TARGET_FEATURES: [(&str, &str); 50] = [
+    ("abm", "lzcnt"),
+    ("adx", "adx"),
+    ("aes", "aes"),
+    ("avx", "avx"),
+    ("avx2", "avx2"),
+    ("avx512_bf16", "avx512bf16"),
+    ("avx512_bitalg", "avx512bitalg"),
+    ("avx512_fp16", "avx512fp16"),
+    ("avx512_vbmi2", "avx512vbmi2"),
+    ("avx512_vnni", "avx512vnni"),
+    ("avx512_vp2intersect", "avx512vp2intersect"),
+    ("avx512_vpopcntdq", "avx512vpopcntdq"),
+    ("avx512bw", "avx512bw"),
+    ("avx512cd", "avx512cd"),
+    ("avx512dq", "avx512dq"),
+    ("avx512f", "avx512f"),
+    ("avx512ifma", "avx512ifma"),
+    ("avx512vbmi", "avx512vbmi"),
+    ("avx512vl", "avx512vl"),
+    ("avx512vnni", "avx512vnni"),
+    ("avx_vnni", "avxvnni"),
+    ("bmi1", "bmi1"),
+    ("bmi2", "bmi2"),
+    ("cx16", "cmpxchg16b"),
+    ("erms", "ermsb"),
+    ("f16c", "f16c"),
+    ("fma", "fma"),
+    ("fxsr", "fxsr"),
+    ("gfni", "gfni"),
+    ("lahf_lm", "lahfsahf"),
+    ("movbe", "movbe"),
+    ("pclmulqdq", "pclmulqdq"),
+    ("pni", "sse3"),
+    ("popcnt", "popcnt"),
+    ("rdrand", "rdrand"),
+    ("rdseed", "rdseed"),
+    ("rtm", "rtm"),
+    ("sha_ni", "sha"),
+    ("sse", "sse"),
+    ("sse2", "sse2"),
+    ("sse4_1", "sse4.1"),
+    ("sse4_2", "sse4.2"),
+    ("sse4a", "sse4a"),
+    ("ssse3", "ssse3"),
+    ("tbm", "tbm"),
+    ("vaes", "vaes"),
+    ("vpclmulqdq", "vpclmulqdq"),
+    ("xsave", "xsave"),
+    ("xsavec", "xsavec"),
+    ("xsaveopt", "xsaveopt"),
+];
+
+/// Kernel CPU flags required by the respective x86-64 micro-architecture level.
+const X86_64_V1: [&str; 8] = ["lm", "cmov", "cx8", "fpu", "fxsr", "mmx", "sse", "sse2"];
+const X86_64_V2: [&str; 7] = [
+    "cx16", "lahf_lm", "popcnt", "pni", "sse4_1", "sse4_2", "ssse3",
+];
+const X86_64_V3: [&str; 9] = [
+    "avx", "avx2", "bmi1", "bmi2", "f16c", "fma", "abm", "movbe", "xsave",
+];
+const X86_64_V4: [&str; 5] = ["avx512f", "avx512bw", "avx512cd", "avx512dq", "avx512vl"];
+
+/// Returns the `rustc` target feature corresponding to the given kernel CPU flag.
+#[must_use]
+pub fn target_feature(flag: &str) -> Option<&'static str> {
+    TARGET_FEATURES
+        .binary_search_by_key(&flag, |&(flag, _)| flag)
+        .ok()
+        .map(|index| TARGET_FEATURES[index].1)
+}
+
+/// The x86-64 micro-architecture levels as defined by the x86-64 psABI.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum X86_64Level {
+    V1,
+    V2,
+    V3,
+    V4,
+}
+
+impl X86_64Level {
+    /// Returns the highest level supported by the given set of kernel CPU flags.
+    #[must_use]
+    pub fn from_flags(flags: &HashSet<&str>) -> Option<Self> {
+        let supports = |required: &[&str]| required.iter().all(|flag| flags.contains(flag));
+
+        if !supports(&X86_64_V1) {
+            None
+        } else if !supports(&X86_64_V2) {
+            Some(Self::V1)
+        } else if !supports(&X86_64_V3) {
+            Some(Self::V2)
+        } else if !supports(&X86_64_V4) {
+            Some(Self::V3)
+        } else {
+            Some(Self::V4)
+        }
+    }
+
+    /// Returns the name of the level as understood by `rustc`'s `-C target-cpu`
+    /// and GCC's and Clang's `-march`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::V1 => "x86-64",
+            Self::V2 => "x86-64-v2",
+            Self::V3 => "x86-64-v3",
+            Self::V4 => "x86-64-v4",
+        }
+    }
+}
+
+impl Display for X86_64Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Cpu<'_> {
+    /// Returns the `rustc` target features supported by the CPU.
+    #[must_use]
+    pub fn target_features(&self) -> BTreeSet<&'static str> {
+        self.flags()
+            .into_iter()
+            .filter_map(target_feature)
+            .collect()
+    }
+
+    /// Returns the highest x86-64 micro-architecture level supported by the CPU.
+    #[must_use]
+    pub fn x86_64_level(&self) -> Option<X86_64Level> {
+        X86_64Level::from_flags(&self.flags())
+    }
+
+    /// Returns the `rustc` codegen option enabling all target features supported by the CPU,
+    /// e.g. `-C target-feature=+aes,+avx,+avx2`, or `None` if it supports none of them.
+    #[must_use]
+    pub fn target_feature_flags(&self) -> Option<String> {
+        let features: Vec<_> = self
+            .target_features()
+            .into_iter()
+            .map(|feature| format!("+{feature}"))
+            .collect();
+        (!features.is_empty()).then(|| format!("-C target-feature={}", features.join(",")))
+    }
+
+    /// Returns the `rustc` codegen option selecting the x86-64 level supported by the CPU,
+    /// e.g. `-C target-cpu=x86-64-v3`.
+    #[must_use]
+    pub fn target_cpu_flags(&self) -> Option<String> {
+        self.x86_64_level()
+            .map(|level| format!("-C target-cpu={level}"))
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::path::Path;
+
+use crate::sysfs::{cpu_dirs, optional, parse, CPU_DIR};
+use crate::{CoreKey, Cpu, SysRoot, Topology};
+
+/// A snapshot of the thermal throttle counters of all logical processors as reported by
+/// `/sys/devices/system/cpu/cpuN/thermal_throttle`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ThrottleSnapshot(BTreeMap<usize, ThrottleCounters>);
+
+impl ThrottleSnapshot {
+    /// Reads the throttle counters from `/sys`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the throttle counters could not be read
+    pub fn read() -> Result<Self, Error> {
+        SysRoot::default().throttle()
+    }
+
+    /// Reads the throttle counters from the given sysfs root.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the throttle counters could not be read
+    pub fn read_from(sysfs_root: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut counters = BTreeMap::new();
+
+        for (processor, path) in cpu_dirs(sysfs_root.as_ref().join(CPU_DIR))? {
+            let path = path.join("thermal_throttle");
+
+            if path.is_dir() {
+                counters.insert(
+                    processor,
+                    ThrottleCounters {
+                        core: Throttle::read_from(&path, "core")?,
+                        package: Throttle::read_from(&path, "package")?,
+                    },
+                );
+            }
+        }
+
+        Ok(Self(counters))
+    }
+
+    #[must_use]
+    pub fn get(&self, processor: usize) -> Option<&ThrottleCounters> {
+        self.0.get(&processor)
+    }
+
+    /// Returns the logical processors and their counters.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &ThrottleCounters)> {
+        self.0
+            .iter()
+            .map(|(&processor, counters)| (processor, counters))
+    }
+
+    /// Returns the events since the given earlier snapshot.
+    ///
+    /// Processors missing from the earlier snapshot, e.g. because they were offline, are skipped.
+    #[must_use]
+    pub fn delta(&self, earlier: &Self) -> Self {
+        Self(
+            self.iter()
+                .filter_map(|(processor, counters)| {
+                    earlier
+                        .get(processor)
+                        .map(|earlier| (processor, counters.delta(earlier)))
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the core throttle counters by physical core.
+    ///
+    /// The counters are shared by the logical processors of a physical core,
+    /// so the counters of its first processor are reported.
+    #[must_use]
+    pub fn cores(&self, topology: &Topology) -> BTreeMap<CoreKey, Throttle> {
+        topology
+            .cores()
+            .filter_map(|(key, core)| {
+                core.processors()
+                    .iter()
+                    .find_map(|processor| self.get(processor)?.core)
+                    .map(|throttle| (key, throttle))
+            })
+            .collect()
+    }
+
+    /// Returns the package throttle counters by socket.
+    ///
+    /// The counters are shared by the logical processors of a package,
+    /// so the counters of its first processor are reported.
+    #[must_use]
+    pub fn packages(&self, topology: &Topology) -> BTreeMap<usize, Throttle> {
+        topology
+            .sockets()
+            .filter_map(|socket| {
+                socket
+                    .processors()
+                    .find_map(|processor| self.get(processor)?.package)
+                    .map(|throttle| (socket.id(), throttle))
+            })
+            .collect()
+    }
+}
+
+/// The throttle counters as seen by a logical processor.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ThrottleCounters {
+    core: Option<Throttle>,
+    package: Option<Throttle>,
+}
+
+impl ThrottleCounters {
+    /// Returns the counters of the processor's physical core.
+    #[must_use]
+    pub const fn core(&self) -> Option<Throttle> {
+        self.core
+    }
+
+    /// Returns the counters of the processor's package, which require the `pts` flag.
+    #[must_use]
+    pub const fn package(&self) -> Option<Throttle> {
+        self.package
+    }
+
+    fn delta(&self, earlier: &Self) -> Self {
+        let delta = |now: Option<Throttle>, earlier: Option<Throttle>| {
+            now.map(|now| earlier.map_or(now, |earlier| now.delta(&earlier)))
+        };
+
+        Self {
+            core: delta(self.core, earlier.core),
+            package: delta(self.package, earlier.package),
+        }
+    }
+}
+
+/// The throttle events of a physical core or package.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Throttle {
+    count: u64,
+    total_time_ms: Option<u64>,
+}
+
+impl Throttle {
+    fn read_from(path: &Path, scope: &str) -> Result<Option<Self>, Error> {
+        let Some(count) = optional(parse(path.join(format!("{scope}_throttle_count"))))? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            count,
+            total_time_ms: optional(parse(path.join(format!("{scope}_throttle_total_time_ms"))))?,
+        }))
+    }
+
+    /// Returns the amount of times the temperature exceeded the throttling threshold.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the total time spent above the throttling threshold in milliseconds,
+    /// which is only reported by newer kernels.
+    #[must_use]
+    pub const fn total_time_ms(&self) -> Option<u64> {
+        self.total_time_ms
+    }
+
+    fn delta(&self, earlier: &Self) -> Self {
+        Self {
+            count: self.count.saturating_sub(earlier.count),
+            total_time_ms: self.total_time_ms.map(|total_time_ms| {
+                total_time_ms.saturating_sub(earlier.total_time_ms.unwrap_or_default())
+            }),
+        }
+    }
+}
+
+impl Cpu<'_> {
+    /// Returns whether the CPU has a digital thermal sensor (`dtherm`).
+    #[must_use]
+    pub fn has_digital_thermal_sensor(&self) -> bool {
+        self.flags().contains("dtherm")
+    }
+
+    /// Returns whether the CPU reports the package thermal status (`pts`).
+    #[must_use]
+    pub fn has_package_thermal_status(&self) -> bool {
+        self.flags().contains("pts")
+    }
+
+    /// Returns whether the CPU notifies about power limit throttling (`pln`).
+    #[must_use]
+    pub fn has_power_limit_notification(&self) -> bool {
+        self.flags().contains("pln")
+    }
+}
+
+impl SysRoot {
+    /// Reads the thermal throttle counters from `/sys/devices/system/cpu`.
+    /// # Errors
+    /// Returns an [`std::io::Error`] if the throttle counters could not be read
+    pub fn throttle(&self) -> Result<ThrottleSnapshot, Error> {
+        ThrottleSnapshot::read_from(self.sys())
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::{CpuInfo, CpuSet, CpuTopology, SysfsTopology};
+
+/// Identifies a physical core by its socket, die, cluster and `core id`,
+/// as core IDs may only be unique per die or cluster.
+pub type CoreKey = (usize, Option<usize>, Option<usize>, usize);
+
+/// The socket / physical core / logical processor hierarchy of a system.
+///
+/// Physical cores additionally carry the die and cluster they belong to,
+/// if known from sysfs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Topology {
+    sockets: BTreeMap<usize, Socket>,
+}
+
+impl Topology {
+    pub fn sockets(&self) -> impl Iterator<Item = &Socket> {
+        self.sockets.values()
+    }
+
+    #[must_use]
+    pub fn socket(&self, id: usize) -> Option<&Socket> {
+        self.sockets.get(&id)
+    }
+
+    #[must_use]
+    pub fn socket_count(&self) -> usize {
+        self.sockets.len()
+    }
+
+    /// Returns the total amount of dies.
+    #[must_use]
+    pub fn die_count(&self) -> usize {
+        self.sockets().map(|socket| socket.dies().len()).sum()
+    }
+
+    /// Returns the total amount of core clusters.
+    #[must_use]
+    pub fn cluster_count(&self) -> usize {
+        self.sockets().map(|socket| socket.clusters().len()).sum()
+    }
+
+    /// Returns all physical cores and their keys.
+    pub fn cores(&self) -> impl Iterator<Item = (CoreKey, &Core)> {
+        self.sockets().flat_map(|socket| {
+            socket
+                .cores
+                .iter()
+                .map(|(&(die, cluster, id), core)| ((socket.id, die, cluster, id), core))
+        })
+    }
+
+    /// Returns the total amount of physical cores.
+    #[must_use]
+    pub fn core_count(&self) -> usize {
+        self.sockets().map(Socket::core_count).sum()
+    }
+
+    /// Returns the total amount of logical processors.
+    #[must_use]
+    pub fn processor_count(&self) -> usize {
+        self.sockets().map(Socket::processor_count).sum()
+    }
+
+    /// Returns the maximum amount of logical processors per physical core.
+    #[must_use]
+    pub fn smt_width(&self) -> usize {
+        self.sockets()
+            .flat_map(Socket::cores)
+            .map(|core| core.processors.len())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the physical core the given logical processor belongs to.
+    #[must_use]
+    pub fn core_of(&self, processor: usize) -> Option<&Core> {
+        self.sockets()
+            .flat_map(Socket::cores)
+            .find(|core| core.processors.contains(processor))
+    }
+
+    /// Returns the socket the given logical processor belongs to.
+    #[must_use]
+    pub fn socket_of(&self, processor: usize) -> Option<&Socket> {
+        self.sockets()
+            .find(|socket| socket.processors().any(|other| other == processor))
+    }
+
+    /// Returns the other logical processors sharing a physical core with the given one.
+    #[must_use]
+    pub fn siblings(&self, processor: usize) -> Option<CpuSet> {
+        self.core_of(processor).map(|core| {
+            core.processors
+                .iter()
+                .filter(|&other| other != processor)
+                .collect()
+        })
+    }
+
+    /// Validates the topology against the `siblings` and `cpu cores` values reported per socket.
+    /// # Errors
+    /// Returns a [`TopologyError`] if the amount of logical processors or physical cores
+    /// of a socket differs from the reported value, e.g. because CPUs are offline
+    pub fn validate(&self) -> Result<(), TopologyError> {
+        self.sockets().try_for_each(Socket::validate)
+    }
+
+    /// Builds the topology from sysfs, falling back to `/proc/cpuinfo`
+    /// for values missing in sysfs.
+    ///
+    /// CPUs without a package ID are considered to be on socket `0`
+    /// and CPUs without a core ID are considered to be a physical core of their own.
+    #[must_use]
+    pub fn merged(cpu_info: &CpuInfo, sysfs: &SysfsTopology) -> Self {
+        let mut topology = Self::default();
+
+        for cpu in cpu_info.cpus() {
+            let Some(processor) = cpu.processor() else {
+                continue;
+            };
+            let sysfs = sysfs.get(processor);
+            let socket = topology.socket_mut(
+                sysfs
+                    .and_then(CpuTopology::physical_package_id)
+                    .or_else(|| cpu.physical_id())
+                    .unwrap_or_default(),
+            );
+            socket.siblings = socket.siblings.or_else(|| cpu.siblings());
+            socket.cpu_cores = socket.cpu_cores.or_else(|| cpu.cpu_cores());
+            socket.insert(
+                processor,
+                sysfs.and_then(CpuTopology::die_id),
+                sysfs.and_then(CpuTopology::cluster_id),
+                sysfs
+                    .and_then(CpuTopology::core_id)
+                    .or_else(|| cpu.core_id())
+                    .unwrap_or(processor),
+            );
+        }
+
+        for sysfs in sysfs.iter() {
+            let processor = sysfs.processor();
+
+            if topology.core_of(processor).is_none() {
+                topology
+                    .socket_mut(sysfs.physical_package_id().unwrap_or_default())
+                    .insert(
+                        processor,
+                        sysfs.die_id(),
+                        sysfs.cluster_id(),
+                        sysfs.core_id().unwrap_or(processor),
+                    );
+            }
+        }
+
+        topology
+    }
+
+    fn socket_mut(&mut self, id: usize) -> &mut Socket {
+        self.sockets.entry(id).or_insert_with(|| Socket::new(id))
+    }
+}
+
+impl CpuInfo {
+    #[must_use]
+    pub fn topology(&self) -> Topology {
+        Topology::from(self)
+    }
+}
+
+impl From<&CpuInfo> for Topology {
+    /// Builds the topology from the `physical id` and `core id` of each CPU.
+    ///
+    /// CPUs without a `physical id` are considered to be on socket `0`
+    /// and CPUs without a `core id` are considered to be a physical core of their own.
+    fn from(cpu_info: &CpuInfo) -> Self {
+        Self::merged(cpu_info, &SysfsTopology::default())
+    }
+}
+
+/// A physical CPU package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Socket {
+    id: usize,
+    cores: BTreeMap<(Option<usize>, Option<usize>, usize), Core>,
+    siblings: Option<usize>,
+    cpu_cores: Option<usize>,
+}
+
+impl Socket {
+    const fn new(id: usize) -> Self {
+        Self {
+            id,
+            cores: BTreeMap::new(),
+            siblings: None,
+            cpu_cores: None,
+        }
+    }
+
+    /// Returns the socket's `physical id`.
+    #[must_use]
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn cores(&self) -> impl Iterator<Item = &Core> {
+        self.cores.values()
+    }
+
+    /// Returns the first core with the given `core id`.
+    ///
+    /// Core IDs may only be unique per die or cluster.
+    #[must_use]
+    pub fn core(&self, id: usize) -> Option<&Core> {
+        self.cores().find(|core| core.id == id)
+    }
+
+    /// Returns the IDs of the socket's dies.
+    #[must_use]
+    pub fn dies(&self) -> BTreeSet<usize> {
+        self.cores().filter_map(|core| core.die).collect()
+    }
+
+    /// Returns the IDs of the socket's core clusters.
+    #[must_use]
+    pub fn clusters(&self) -> BTreeSet<(Option<usize>, usize)> {
+        self.cores()
+            .filter_map(|core| core.cluster.map(|cluster| (core.die, cluster)))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn core_count(&self) -> usize {
+        self.cores.len()
+    }
+
+    /// Returns the logical processors of the socket.
+    pub fn processors(&self) -> impl Iterator<Item = usize> + '_ {
+        self.cores().flat_map(|core| core.processors.iter())
+    }
+
+    #[must_use]
+    pub fn processor_count(&self) -> usize {
+        self.cores().map(|core| core.processors.len()).sum()
+    }
+
+    fn insert(&mut self, processor: usize, die: Option<usize>, cluster: Option<usize>, id: usize) {
+        self.cores
+            .entry((die, cluster, id))
+            .or_insert_with(|| Core {
+                id,
+                die,
+                cluster,
+                processors: CpuSet::new(),
+            })
+            .processors
+            .insert(processor);
+    }
+
+    fn validate(&self) -> Result<(), TopologyError> {
+        if let Some(expected) = self.siblings {
+            let actual = self.processor_count();
+
+            if actual != expected {
+                return Err(TopologyError::Siblings {
+                    socket: self.id,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        if let Some(expected) = self.cpu_cores {
+            let actual = self.core_count();
+
+            if actual != expected {
+                return Err(TopologyError::CpuCores {
+                    socket: self.id,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A physical core.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Core {
+    id: usize,
+    die: Option<usize>,
+    cluster: Option<usize>,
+    processors: CpuSet,
+}
+
+impl Core {
+    /// Returns the core's `core id`.
+    #[must_use]
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the ID of the die the core is on.
+    #[must_use]
+    pub const fn die(&self) -> Option<usize> {
+        self.die
+    }
+
+    /// Returns the ID of the cluster the core belongs to.
+    #[must_use]
+    pub const fn cluster(&self) -> Option<usize> {
+        self.cluster
+    }
+
+    /// Returns the logical processors of the core.
+    #[must_use]
+    pub const fn processors(&self) -> &CpuSet {
+        &self.processors
+    }
+}
+
+/// Mismatches between a [`Topology`] and the values reported in `/proc/cpuinfo`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TopologyError {
+    Siblings {
+        socket: usize,
+        expected: usize,
+        actual: usize,
+    },
+    CpuCores {
+        socket: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl Display for TopologyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Siblings {
+                socket,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "socket {socket} reports {expected} siblings, but has {actual} logical processors"
+            ),
+            Self::CpuCores {
+                socket,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "socket {socket} reports {expected} cpu cores, but has {actual} physical cores"
+            ),
+        }
+    }
+}
+
+impl Error for TopologyError {}
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+use crate::Cpu;
+
+/// Known x86 CPU vendors as identified by their `vendor_id`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Vendor {
+    Intel,
+    Amd,
+    Hygon,
+    Zhaoxin,
+    Centaur,
+}
+
+impl Vendor {
+    /// Returns the vendor corresponding to the given `vendor_id`.
+    #[must_use]
+    pub fn from_vendor_id(vendor_id: &str) -> Option<Self> {
+        match vendor_id.trim() {
+            "GenuineIntel" => Some(Self::Intel),
+            "AuthenticAMD" => Some(Self::Amd),
+            "HygonGenuine" => Some(Self::Hygon),
+            "Shanghai" => Some(Self::Zhaoxin),
+            "CentaurHauls" => Some(Self::Centaur),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Vendor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Intel => write!(f, "Intel"),
+            Self::Amd => write!(f, "AMD"),
+            Self::Hygon => write!(f, "Hygon"),
+            Self::Zhaoxin => write!(f, "Zhaoxin"),
+            Self::Centaur => write!(f, "Centaur"),
+        }
+    }
+}
+
+impl Cpu<'_> {
+    #[must_use]
+    pub fn vendor(&self) -> Option<Vendor> {
+        self.vendor_id().and_then(Vendor::from_vendor_id)
+    }
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, CpuSet, SysRoot};
+
+const CPU_INFO: &str = "processor	: 0
+core id		: 0
+
+processor	: 1
+core id		: 1
+
+processor	: 2
+core id		: 2
+
+processor	: 3
+core id		: 3
+";
+
+const STATUS: &str = "Name:	cat
+Cpus_allowed:	e
+Cpus_allowed_list:	1-3
+Mems_allowed_list:	0";
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cgroup_v2() {
+    let fixture = Fixture::new()
+        .file("proc/cpuinfo", CPU_INFO)
+        .file("proc/self/status", STATUS)
+        .file("proc/self/cgroup", "0::/docker/abc")
+        .file("sys/fs/cgroup/cpuset.cpus.effective", "0-3")
+        .file("sys/fs/cgroup/docker/abc/cpuset.cpus.effective", "0-2");
+    let sysroot = SysRoot::from(fixture.path());
+    assert_eq!(
+        sysroot.cpus_allowed_list().unwrap(),
+        CpuSet::from([1, 2, 3])
+    );
+    assert_eq!(
+        sysroot.cgroup_cpus().unwrap(),
+        Some(CpuSet::from([0, 1, 2]))
+    );
+    assert_eq!(sysroot.allowed_cpus().unwrap(), CpuSet::from([1, 2]));
+    let cpu_info = sysroot.allowed_cpu_info().unwrap();
+    assert_eq!(
+        cpu_info
+            .cpus()
+            .filter_map(|cpu| cpu.core_id())
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cgroup_v1_ancestor() {
+    let fixture = Fixture::new()
+        .file("proc/self/status", STATUS)
+        .file("proc/self/cgroup", "4:memory:/a/b\n3:cpuset:/a/b\n0::/")
+        .file("sys/fs/cgroup/cpuset/a/cpuset.effective_cpus", "2-3");
+    let sysroot = SysRoot::from(fixture.path());
+    assert_eq!(sysroot.cgroup_cpus().unwrap(), Some(CpuSet::from([2, 3])));
+    assert_eq!(sysroot.allowed_cpus().unwrap(), CpuSet::from([2, 3]));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_no_cgroup() {
+    let fixture = Fixture::new()
+        .file("proc/self/status", STATUS)
+        .file("proc/self/cgroup", "0::/");
+    let sysroot = SysRoot::from(fixture.path());
+    assert_eq!(sysroot.cgroup_cpus().unwrap(), None);
+    assert_eq!(sysroot.allowed_cpus().unwrap(), CpuSet::from([1, 2, 3]));
+}
+
+#[test]
+fn test_restrict() {
+    let cpu_info = CpuInfo::from(CPU_INFO).restrict(&CpuSet::from([0, 3, 7]));
+    assert_eq!(
+        cpu_info
+            .cpus()
+            .filter_map(|cpu| cpu.processor())
+            .collect::<Vec<_>>(),
+        [0, 3]
+    );
+    assert_eq!(cpu_info.topology().core_count(), 2);
+}
+
+#[cfg(feature = "libc")]
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_affinity() {
+    assert!(!CpuSet::affinity().unwrap().is_empty());
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "archspec")]
+
+use proc_cpuinfo::archspec::{Database, Error};
+use proc_cpuinfo::CpuInfo;
+use std::str::FromStr;
+
+const CPU_INFO: &str = "processor	: 0
+vendor_id	: GenuineIntel
+cpu family	: 6
+model		: 151
+model name	: 12th Gen Intel(R) Core(TM) i5-12400
+stepping	: 5
+microcode	: 0x2c
+cpu MHz		: 2500.000
+cache size	: 18432 KB
+physical id	: 0
+siblings	: 12
+core id		: 0
+cpu cores	: 6
+apicid		: 0
+initial apicid	: 0
+fpu		: yes
+fpu_exception	: yes
+cpuid level	: 32
+wp		: yes
+flags		: fpu vme de pse tsc msr pae mce cx8 apic sep mtrr pge mca cmov pat pse36 clflush dts acpi mmx fxsr sse sse2 ss ht tm pbe syscall nx pdpe1gb rdtscp lm constant_tsc art arch_perfmon pebs bts rep_good nopl xtopology nonstop_tsc cpuid aperfmperf tsc_known_freq pni pclmulqdq dtes64 monitor ds_cpl vmx est tm2 ssse3 sdbg fma cx16 xtpr pdcm sse4_1 sse4_2 x2apic movbe popcnt tsc_deadline_timer aes xsave avx f16c rdrand lahf_lm abm 3dnowprefetch cpuid_fault epb cat_l2 cdp_l2 ssbd ibrs ibpb stibp ibrs_enhanced tpr_shadow flexpriority ept vpid ept_ad fsgsbase tsc_adjust bmi1 avx2 smep bmi2 erms invpcid rdt_a rdseed adx smap clflushopt clwb intel_pt sha_ni xsaveopt xsavec xgetbv1 xsaves split_lock_detect avx_vnni dtherm ida arat pln pts hwp hwp_notify hwp_act_window hwp_epp hwp_pkg_req hfi vnmi umip pku ospke waitpkg gfni vaes vpclmulqdq rdpid movdiri movdir64b fsrm md_clear serialize arch_lbr ibt flush_l1d arch_capabilities
+vmx flags	: vnmi preemption_timer posted_intr invvpid ept_x_only ept_ad ept_1gb flexpriority apicv tsc_offset vtpr mtf vapic ept vpid unrestricted_guest vapic_reg vid ple shadow_vmcs pml ept_mode_based_exec tsc_scaling usr_wait_pause
+bugs		: spectre_v1 spectre_v2 spec_store_bypass swapgs eibrs_pbrsb
+bogomips	: 4993.00
+clflush size	: 64
+cache_alignment	: 64
+address sizes	: 39 bits physical, 48 bits virtual
+power management:
+";
+
+const DATABASE: &str = r#"{
+  "microarchitectures": {
+    "x86_64": {"from": [], "vendor": "generic", "features": []},
+    "x86_64_v2": {
+      "from": ["x86_64"],
+      "vendor": "generic",
+      "features": ["cx16", "lahf_lm", "mmx", "sse", "sse2", "ssse3", "sse4_1", "sse4_2", "popcnt"]
+    },
+    "goldencove": {"from": ["x86_64_v2"], "vendor": "GenuineIntel", "features": ["avx2", "avx_vnni"]}
+  }
+}"#;
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_bundled() {
+    let database = Database::bundled();
+    let target = database.get("haswell").unwrap();
+    assert_eq!(target.vendor(), "GenuineIntel");
+    assert!(target.ancestors().contains("x86_64_v3"));
+    assert!(target.features().contains("avx2"));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_best_match() {
+    let database = Database::bundled();
+    let cpu_info = CpuInfo::from_str(CPU_INFO).unwrap();
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert_eq!(database.best_match(&cpu).unwrap().name(), "skylake");
+    let amd = CPU_INFO.replace("GenuineIntel", "AuthenticAMD");
+    let cpu_info = CpuInfo::from_str(&amd).unwrap();
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert_eq!(database.best_match(&cpu).unwrap().name(), "x86_64_v3");
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_custom_database() {
+    let database = Database::from_str(DATABASE).unwrap();
+    let cpu_info = CpuInfo::from_str(CPU_INFO).unwrap();
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert_eq!(database.best_match(&cpu).unwrap().name(), "goldencove");
+    assert_eq!(database.compatible(&cpu).len(), 3);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_feature_aliases() {
+    let cpu_info = CpuInfo::from_str(
+        "processor	: 0\nvendor_id	: GenuineIntel\nflags		: fpu lm mmx sse sse2 pni cx16\n",
+    )
+    .unwrap();
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert_eq!(
+        Database::bundled().best_match(&cpu).unwrap().name(),
+        "nocona"
+    );
+
+    let database = Database::from_str(
+        r#"{
+          "microarchitectures": {
+            "x86_64": {"from": [], "vendor": "generic", "features": []},
+            "a": {"from": ["x86_64"], "vendor": "generic", "features": ["sse3", "x"]}
+          },
+          "feature_aliases": {
+            "sse3": {"reason": "superset", "any_of": ["ssse3"]},
+            "x": {"reason": "implied", "families": ["x86_64"]}
+          }
+        }"#,
+    )
+    .unwrap();
+    let cpu_info = CpuInfo::from_str("processor	: 0\nflags		: lm ssse3\n").unwrap();
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert_eq!(database.best_match(&cpu).unwrap().name(), "a");
+    let cpu_info = CpuInfo::from_str("processor	: 0\nflags		: lm sse2\n").unwrap();
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert_eq!(database.best_match(&cpu).unwrap().name(), "x86_64");
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_non_x86_64() {
+    let cpu_info = CpuInfo::from_str("processor	: 0\nflags		: fp asimd\n").unwrap();
+    assert!(Database::bundled()
+        .best_match(&cpu_info.cpu(0).unwrap())
+        .is_none());
+}
+
+#[test]
+fn test_invalid_database() {
+    assert!(matches!(
+        Database::from_str(
+            r#"{"microarchitectures": {"a": {"from": ["b"], "vendor": "generic"}}}"#
+        ),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(Database::from_str("{"), Err(Error::Json(_))));
+    assert!(matches!(
+        Database::read_from("/nonexistent/microarchitectures.json"),
+        Err(Error::Io(_))
+    ));
+}
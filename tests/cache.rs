@@ -0,0 +1,79 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CacheType, Caches, CpuSet, SysRoot};
+
+/// Two physical cores with two threads each, with private L1 and L2 caches and a shared L3.
+fn fixture() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    for cpu in 0..4 {
+        let core = if cpu % 2 == 0 { "0,2" } else { "1,3" };
+
+        for (index, level, kind, size, ways, sets, shared) in [
+            (0, 1, "Data", "48K", 12, 64, core),
+            (1, 1, "Instruction", "32K", 8, 64, core),
+            (2, 2, "Unified", "1280K", 10, 2048, core),
+            (3, 3, "Unified", "18432K", 12, 24576, "0-3"),
+        ] {
+            let path = format!("sys/devices/system/cpu/cpu{cpu}/cache/index{index}");
+            fixture = fixture
+                .file(format!("{path}/level"), level.to_string())
+                .file(format!("{path}/type"), kind)
+                .file(format!("{path}/size"), size)
+                .file(format!("{path}/ways_of_associativity"), ways.to_string())
+                .file(format!("{path}/coherency_line_size"), "64")
+                .file(format!("{path}/number_of_sets"), sets.to_string())
+                .file(format!("{path}/shared_cpu_list"), shared);
+        }
+    }
+
+    fixture
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_read() {
+    let fixture = fixture();
+    let caches = SysRoot::from(fixture.path()).caches().unwrap();
+    assert_eq!(caches.iter().count(), 4);
+    let cpu = caches.get(2).unwrap();
+    assert_eq!(cpu.len(), 4);
+    assert_eq!(cpu[0].level(), 1);
+    assert_eq!(cpu[0].kind(), CacheType::Data);
+    assert_eq!(cpu[0].size(), Some(48 * 1024));
+    assert_eq!(cpu[0].ways_of_associativity(), Some(12));
+    assert_eq!(cpu[0].coherency_line_size(), Some(64));
+    assert_eq!(cpu[0].number_of_sets(), Some(64));
+    assert_eq!(cpu[0].shared_cpus(), &CpuSet::from([0, 2]));
+    assert_eq!(cpu[3].size(), Some(18 * 1024 * 1024));
+    assert_eq!(cpu[3].shared_cpus(), &CpuSet::from([0, 1, 2, 3]));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_instances() {
+    let caches = Caches::read_from(fixture().path().join("sys")).unwrap();
+    let instances = caches.instances();
+    assert_eq!(instances.len(), 7);
+    assert_eq!(
+        instances.iter().filter(|cache| cache.level() == 3).count(),
+        1
+    );
+    assert_eq!(
+        instances
+            .iter()
+            .filter(|cache| cache.kind() == CacheType::Instruction)
+            .map(|cache| cache.shared_cpus().clone())
+            .collect::<Vec<_>>(),
+        [CpuSet::from([0, 2]), CpuSet::from([1, 3])]
+    );
+}
+
+#[test]
+fn test_invalid_type() {
+    let fixture = Fixture::new()
+        .file("sys/devices/system/cpu/cpu0/cache/index0/level", "1")
+        .file("sys/devices/system/cpu/cpu0/cache/index0/type", "Bogus");
+    assert!(SysRoot::from(fixture.path()).caches().is_err());
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, CpuSet, SysRoot};
+
+/// A DynamIQ system with four Cortex-A55, three Cortex-A78 and one Cortex-X1 core.
+fn cpu_info() -> String {
+    (0..8)
+        .map(|processor| {
+            let part = match processor {
+                0..=3 => "0xd05",
+                4..=6 => "0xd41",
+                _ => "0xd44",
+            };
+            format!(
+                "processor	: {processor}\nBogoMIPS	: 38.40\nCPU implementer	: 0x41\n\
+                 CPU architecture: 8\nCPU variant	: 0x1\nCPU part	: {part}\nCPU revision	: 0\n"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn fixture() -> Fixture {
+    let mut fixture = Fixture::new().file("proc/cpuinfo", cpu_info());
+
+    for (processor, capacity) in [120, 120, 120, 120, 650, 650, 650, 1024]
+        .into_iter()
+        .enumerate()
+    {
+        fixture = fixture.file(
+            format!("sys/devices/system/cpu/cpu{processor}/cpu_capacity"),
+            capacity.to_string(),
+        );
+    }
+
+    fixture
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cpu_getters() {
+    let cpu_info = CpuInfo::from(cpu_info());
+    let cpu = cpu_info.cpu(7).unwrap();
+    assert_eq!(cpu.cpu_implementer(), Some(0x41));
+    assert_eq!(cpu.cpu_architecture(), Some(8));
+    assert_eq!(cpu.cpu_variant(), Some(1));
+    assert_eq!(cpu.cpu_part(), Some(0xd44));
+    assert_eq!(cpu.cpu_revision(), Some(0));
+    assert_eq!(cpu.cpu_part_name(), Some("Cortex-X1"));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_clusters() {
+    let fixture = fixture();
+    let clusters = SysRoot::from(fixture.path()).cpu_clusters().unwrap();
+    assert_eq!(clusters.len(), 3);
+    assert_eq!(
+        clusters
+            .iter()
+            .map(|cluster| (cluster.part_name(), cluster.capacity()))
+            .collect::<Vec<_>>(),
+        [
+            (Some("Cortex-A55"), Some(120)),
+            (Some("Cortex-A78"), Some(650)),
+            (Some("Cortex-X1"), Some(1024))
+        ]
+    );
+    assert_eq!(clusters.little(), CpuSet::from([0, 1, 2, 3]));
+    assert_eq!(clusters.big(), CpuSet::from([4, 5, 6, 7]));
+    assert_eq!(clusters.prime(), CpuSet::from([7]));
+    assert_eq!(clusters.cluster_of(5).unwrap().part(), Some(0xd41));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_homogeneous() {
+    let fixture = Fixture::new().file(
+        "proc/cpuinfo",
+        cpu_info()
+            .replace("0xd41", "0xd05")
+            .replace("0xd44", "0xd05"),
+    );
+    let clusters = SysRoot::from(fixture.path()).cpu_clusters().unwrap();
+    assert_eq!(clusters.len(), 1);
+    assert!(clusters.little().is_empty());
+    assert_eq!(clusters.big().len(), 8);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_without_capacities() {
+    // A Kryo 280 system with four Gold and four Silver cores.
+    let fixture = Fixture::new().file(
+        "proc/cpuinfo",
+        cpu_info()
+            .replace("0x41", "0x51")
+            .replace("0xd05", "0x801")
+            .replace("0xd41", "0x800")
+            .replace("0xd44", "0x800"),
+    );
+    let clusters = SysRoot::from(fixture.path()).cpu_clusters().unwrap();
+    assert_eq!(clusters.len(), 2);
+    assert_eq!(
+        clusters.cluster_of(0).unwrap().part_name(),
+        Some("Kryo 2xx Silver")
+    );
+    assert_eq!(clusters.cluster_of(0).unwrap().capacity(), None);
+    assert!(clusters.little().is_empty());
+    assert!(clusters.big().is_empty());
+    assert!(clusters.prime().is_empty());
+}
@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::path::{Path, PathBuf};
+use std::process::id;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A temporary directory tree, which is removed when dropped.
+pub struct Fixture(PathBuf);
+
+impl Fixture {
+    pub fn new() -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "proc-cpuinfo-{}-{}",
+            id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        create_dir_all(&path).expect("could not create fixture directory");
+        Self(path)
+    }
+
+    /// Writes the given file relative to the fixture's root.
+    pub fn file(self, path: impl AsRef<Path>, contents: impl AsRef<str>) -> Self {
+        let path = self.0.join(path);
+
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent).expect("could not create fixture directory");
+        }
+
+        write(path, format!("{}\n", contents.as_ref())).expect("could not write fixture file");
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuControls, CpuInfo, SmtControl, SysRoot};
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_intel() {
+    let fixture = Fixture::new()
+        .file("proc/cpuinfo", "processor	: 0\nflags		: fpu ht ida hwp")
+        .file("sys/devices/system/cpu/intel_pstate/no_turbo", "1")
+        .file("sys/devices/system/cpu/intel_pstate/status", "active")
+        .file("sys/devices/system/cpu/smt/control", "on")
+        .file("sys/devices/system/cpu/smt/active", "1");
+    let controls = SysRoot::from(fixture.path()).cpu_controls().unwrap();
+    assert_eq!(controls.boost(), None);
+    assert_eq!(controls.intel_pstate_no_turbo(), Some(true));
+    assert_eq!(controls.intel_pstate_status(), Some("active"));
+    assert_eq!(controls.amd_pstate_status(), None);
+    assert_eq!(controls.turbo_enabled(), Some(false));
+    assert_eq!(controls.smt_control(), Some(SmtControl::On));
+    assert_eq!(controls.smt_active(), Some(true));
+    assert!(controls.turbo_capable());
+    assert!(controls.smt_capable());
+    assert!(controls.hwp_capable());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_amd() {
+    let fixture = Fixture::new()
+        .file("sys/devices/system/cpu/cpufreq/boost", "1")
+        .file("sys/devices/system/cpu/amd_pstate/status", "guided")
+        .file("sys/devices/system/cpu/smt/control", "forceoff")
+        .file("sys/devices/system/cpu/smt/active", "0");
+    let cpu_info = CpuInfo::from("processor	: 0\nflags		: fpu ht cpb");
+    let controls = CpuControls::read_from(fixture.path().join("sys"), &cpu_info).unwrap();
+    assert_eq!(controls.turbo_enabled(), Some(true));
+    assert_eq!(controls.amd_pstate_status(), Some("guided"));
+    assert_eq!(controls.smt_control(), Some(SmtControl::ForceOff));
+    assert_eq!(controls.smt_active(), Some(false));
+    assert!(controls.turbo_capable());
+    assert!(!controls.hwp_capable());
+}
+
+#[test]
+fn test_smt_control() {
+    assert_eq!("notsupported".parse(), Ok(SmtControl::NotSupported));
+    assert_eq!("4".parse(), Ok(SmtControl::Threads(4)));
+    assert!("maybe".parse::<SmtControl>().is_err());
+    assert_eq!(SmtControl::ForceOff.to_string(), "forceoff");
+}
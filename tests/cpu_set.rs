@@ -0,0 +1,116 @@
+use proc_cpuinfo::{CpuInfo, CpuSet, ParseCpuSetError};
+
+const CPU_INFO: &str = "processor	: 0
+core id		: 0
+
+processor	: 1
+core id		: 1
+
+processor	: 2
+core id		: 0
+
+processor	: 3
+core id		: 1
+";
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_parse_cpulist() {
+    let cpus: CpuSet = "0-3,8-11,16".parse().unwrap();
+    assert_eq!(cpus.len(), 9);
+    assert!(cpus.contains(9));
+    assert!(!cpus.contains(12));
+    assert_eq!(cpus.first(), Some(0));
+    assert_eq!(cpus.last(), Some(16));
+    assert!("".parse::<CpuSet>().unwrap().is_empty());
+    assert_eq!(
+        "3-1".parse::<CpuSet>(),
+        Err(ParseCpuSetError::InvalidEntry("3-1".to_string()))
+    );
+    assert!("0,a".parse::<CpuSet>().is_err());
+    assert_eq!(
+        "0-18446744073709551615".parse::<CpuSet>(),
+        Err(ParseCpuSetError::InvalidEntry(
+            "0-18446744073709551615".to_string()
+        ))
+    );
+    assert!("65536".parse::<CpuSet>().is_err());
+    assert_eq!("65535".parse::<CpuSet>().unwrap().last(), Some(65535));
+}
+
+#[test]
+fn test_format_cpulist() {
+    assert_eq!(
+        CpuSet::from([0, 1, 2, 3, 8, 9, 10, 11, 16]).to_string(),
+        "0-3,8-11,16"
+    );
+    assert_eq!(CpuSet::from([0, 1]).to_string(), "0-1");
+    assert_eq!(CpuSet::new().to_string(), "");
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_mask() {
+    assert_eq!(CpuSet::from_mask("fff").unwrap(), (0..12).collect());
+    assert_eq!(
+        CpuSet::from_mask("1,00000001").unwrap(),
+        CpuSet::from([0, 32])
+    );
+    assert_eq!(
+        CpuSet::from_mask("0xf0").unwrap(),
+        CpuSet::from([4, 5, 6, 7])
+    );
+    assert!(CpuSet::from_mask("fg").is_err());
+    assert_eq!(
+        CpuSet::from_mask(&format!("8{}", "0".repeat(16383))).unwrap(),
+        CpuSet::from([65535])
+    );
+    assert!(CpuSet::from_mask(&format!("1{}", "0".repeat(16384))).is_err());
+    assert!(CpuSet::from_mask(&"f".repeat(20000)).is_err());
+    assert_eq!(
+        CpuSet::from_mask(&format!("0{}", "f".repeat(16384)))
+            .unwrap()
+            .len(),
+        65536
+    );
+    assert_eq!(CpuSet::from([0, 32]).to_mask(), "1,00000001");
+    assert_eq!((0..12).collect::<CpuSet>().to_mask(), "fff");
+    assert_eq!(CpuSet::new().to_mask(), "0");
+}
+
+#[test]
+fn test_set_operations() {
+    let a = CpuSet::from([0, 1, 2]);
+    let b = CpuSet::from([2, 3]);
+    assert_eq!(&a | &b, CpuSet::from([0, 1, 2, 3]));
+    assert_eq!(&a & &b, CpuSet::from([2]));
+    assert_eq!(&a - &b, CpuSet::from([0, 1]));
+    assert!(CpuSet::from([1, 2]).is_subset(&a));
+    assert!(!a.is_disjoint(&b));
+    assert_eq!(a.iter().collect::<Vec<_>>(), [0, 1, 2]);
+}
+
+#[test]
+fn test_cpu_info_selection() {
+    let cpu_info = CpuInfo::from(CPU_INFO);
+    assert_eq!(
+        cpu_info.cpu_set(|cpu| cpu.core_id() == Some(0)),
+        CpuSet::from([0, 2])
+    );
+}
+
+#[cfg(feature = "libc")]
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_libc() {
+    let cpus = CpuSet::from([0, 5, 63]);
+    let set = libc::cpu_set_t::try_from(&cpus).unwrap();
+    assert_eq!(CpuSet::from(&set), cpus);
+    assert!(libc::cpu_set_t::try_from(&CpuSet::from([1024])).is_err());
+}
+
+#[test]
+#[should_panic(expected = "exceeds the maximum")]
+fn test_insert_beyond_maximum() {
+    let _ = CpuSet::from([usize::MAX]);
+}
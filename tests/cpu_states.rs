@@ -0,0 +1,99 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, CpuSet, CpuState, CpuStates, SysRoot};
+
+const CPU_INFO: &str = "processor	: 0
+core id		: 0
+
+processor	: 2
+core id		: 2
+";
+
+fn fixture() -> Fixture {
+    Fixture::new()
+        .file("sys/devices/system/cpu/online", "0,2")
+        .file("sys/devices/system/cpu/offline", "1,3-7")
+        .file("sys/devices/system/cpu/possible", "0-7")
+        .file("sys/devices/system/cpu/present", "0-3")
+        .file("sys/devices/system/cpu/isolated", "2")
+        .file("sys/devices/system/cpu/nohz_full", "")
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_sets() {
+    let fixture = fixture();
+    let states = SysRoot::from(fixture.path()).cpu_states().unwrap();
+    assert_eq!(states.online(), &CpuSet::from([0, 2]));
+    assert_eq!(states.offline(), &CpuSet::from([1, 3, 4, 5, 6, 7]));
+    assert_eq!(states.possible(), &(0..8).collect());
+    assert_eq!(states.present(), &(0..4).collect());
+    assert_eq!(states.isolated(), &CpuSet::from([2]));
+    assert!(states.nohz_full().is_empty());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_states() {
+    let states = CpuStates::read_from(fixture().path().join("sys")).unwrap();
+    assert_eq!(states.state(0), CpuState::Online);
+    assert_eq!(states.state(1), CpuState::Offline);
+    assert_eq!(states.state(7), CpuState::NotPresent);
+    assert_eq!(states.state(8), CpuState::Impossible);
+    assert_eq!(states.states().len(), 8);
+    assert_eq!(CpuState::NotPresent.to_string(), "not present");
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_annotate() {
+    let states = CpuStates::read_from(fixture().path().join("sys")).unwrap();
+    let cpu_info = CpuInfo::from(CPU_INFO);
+    let annotated = states.annotate(&cpu_info);
+    assert_eq!(annotated.len(), 8);
+    let (state, cpu) = &annotated[&2];
+    assert_eq!(*state, CpuState::Online);
+    assert_eq!(cpu.as_ref().and_then(|cpu| cpu.core_id()), Some(2));
+    let (state, cpu) = &annotated[&1];
+    assert_eq!(*state, CpuState::Offline);
+    assert!(cpu.is_none());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_missing_files() {
+    let fixture = Fixture::new()
+        .file("sys/devices/system/cpu/online", "0-3")
+        .file("sys/devices/system/cpu/possible", "0-3")
+        .file("sys/devices/system/cpu/present", "0-3");
+    let states = CpuStates::read_from(fixture.path().join("sys")).unwrap();
+    assert_eq!(states.online().len(), 4);
+    assert!(states.offline().is_empty());
+    assert!(states.isolated().is_empty());
+    assert!(states.nohz_full().is_empty());
+}
+
+#[test]
+fn test_required_files() {
+    assert!(CpuStates::read_from("/nonexistent").is_err());
+    let fixture = Fixture::new()
+        .file("sys/devices/system/cpu/online", "0-3")
+        .file("sys/devices/system/cpu/present", "0-3");
+    assert!(CpuStates::read_from(fixture.path().join("sys")).is_err());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_null() {
+    let fixture = fixture().file("sys/devices/system/cpu/nohz_full", "(null)");
+    let states = CpuStates::read_from(fixture.path().join("sys")).unwrap();
+    assert_eq!(states.online(), &CpuSet::from([0, 2]));
+    assert!(states.nohz_full().is_empty());
+}
+
+#[test]
+fn test_invalid() {
+    let fixture = fixture().file("sys/devices/system/cpu/isolated", "x");
+    assert!(CpuStates::read_from(fixture.path().join("sys")).is_err());
+}
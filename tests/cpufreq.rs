@@ -0,0 +1,66 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuFreqs, CpuInfo, SysRoot};
+
+const CPU_INFO: &str = "processor	: 0
+cpu MHz		: 800.000
+
+processor	: 1
+cpu MHz		: 800.000
+";
+
+fn fixture() -> Fixture {
+    let dir = "sys/devices/system/cpu/cpu0/cpufreq";
+    Fixture::new()
+        .file(format!("{dir}/cpuinfo_min_freq"), "800000")
+        .file(format!("{dir}/cpuinfo_max_freq"), "4400000")
+        .file(format!("{dir}/base_frequency"), "2500000")
+        .file(format!("{dir}/scaling_cur_freq"), "3900123")
+        .file(format!("{dir}/scaling_governor"), "powersave")
+        .file(format!("{dir}/scaling_driver"), "intel_pstate")
+        .file(
+            format!("{dir}/energy_performance_preference"),
+            "balance_performance",
+        )
+        .file(
+            "sys/devices/system/cpu/cpu1/cpufreq/scaling_driver",
+            "acpi-cpufreq",
+        )
+        .file("sys/devices/system/cpu/cpu2/online", "0")
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cpufreq() {
+    let fixture = fixture();
+    let cpufreqs = SysRoot::from(fixture.path()).cpufreqs().unwrap();
+    assert_eq!(cpufreqs.iter().count(), 2);
+    let cpufreq = cpufreqs.get(0).unwrap();
+    assert_eq!(cpufreq.processor(), 0);
+    assert_eq!(cpufreq.min_freq(), Some(800_000));
+    assert_eq!(cpufreq.max_freq(), Some(4_400_000));
+    assert_eq!(cpufreq.base_frequency(), Some(2_500_000));
+    assert_eq!(cpufreq.cur_freq(), Some(3_900_123));
+    assert_eq!(cpufreq.governor(), Some("powersave"));
+    assert_eq!(cpufreq.driver(), Some("intel_pstate"));
+    assert_eq!(
+        cpufreq.energy_performance_preference(),
+        Some("balance_performance")
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cpu() {
+    let cpufreqs = CpuFreqs::read_from(fixture().path().join("sys")).unwrap();
+    let cpu_info = CpuInfo::from(CPU_INFO);
+    let cpufreq = cpu_info.cpu(1).unwrap().cpufreq(&cpufreqs).unwrap();
+    assert_eq!(cpufreq.driver(), Some("acpi-cpufreq"));
+    assert_eq!(cpufreq.base_frequency(), None);
+    assert!(CpuInfo::from("processor	: 2")
+        .cpu(2)
+        .unwrap()
+        .cpufreq(&cpufreqs)
+        .is_none());
+}
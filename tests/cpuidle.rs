@@ -0,0 +1,57 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuIdles, CpuInfo, SysRoot};
+
+fn fixture() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    for (index, name, latency, residency, disable, usage, time) in [
+        (0, "POLL", 0, 0, 0, 10, 100),
+        (1, "C1", 1, 1, 0, 20, 2000),
+        (2, "C6", 170, 600, 0, 30, 30000),
+        (3, "C10", 890, 5000, 1, 0, 0),
+    ] {
+        let dir = format!("sys/devices/system/cpu/cpu0/cpuidle/state{index}");
+        fixture = fixture
+            .file(format!("{dir}/name"), name)
+            .file(format!("{dir}/desc"), format!("MWAIT {name}"))
+            .file(format!("{dir}/latency"), latency.to_string())
+            .file(format!("{dir}/residency"), residency.to_string())
+            .file(format!("{dir}/disable"), disable.to_string())
+            .file(format!("{dir}/usage"), usage.to_string())
+            .file(format!("{dir}/time"), time.to_string());
+    }
+
+    fixture
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_states() {
+    let fixture = fixture();
+    let cpuidles = SysRoot::from(fixture.path()).cpuidles().unwrap();
+    let cpuidle = cpuidles.get(0).unwrap();
+    assert_eq!(cpuidle.processor(), 0);
+    assert_eq!(cpuidle.states().len(), 4);
+    let state = &cpuidle.states()[2];
+    assert_eq!(state.index(), 2);
+    assert_eq!(state.name(), "C6");
+    assert_eq!(state.desc(), Some("MWAIT C6"));
+    assert_eq!(state.latency(), 170);
+    assert_eq!(state.residency(), 600);
+    assert!(!state.disabled());
+    assert_eq!(state.usage(), 30);
+    assert_eq!(state.time(), 30000);
+    assert!(cpuidle.states()[3].disabled());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_summary() {
+    let cpuidles = CpuIdles::read_from(fixture().path().join("sys")).unwrap();
+    let cpu_info = CpuInfo::from("processor	: 0");
+    let cpuidle = cpu_info.cpu(0).unwrap().cpuidle(&cpuidles).unwrap();
+    assert_eq!(cpuidle.deepest_enabled().unwrap().name(), "C6");
+    assert_eq!(cpuidle.total_time(), 32100);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, SysRoot, Temperatures};
+
+const CPU_INFO: &str = "processor	: 0
+physical id	: 0
+core id		: 0
+
+processor	: 1
+physical id	: 0
+core id		: 4
+
+processor	: 2
+physical id	: 1
+core id		: 0
+";
+
+fn coretemp(fixture: Fixture, hwmon: usize, sensors: &[(usize, &str, i64)]) -> Fixture {
+    let dir = format!("sys/class/hwmon/hwmon{hwmon}");
+    let mut fixture = fixture.file(format!("{dir}/name"), "coretemp");
+
+    for &(index, label, millis) in sensors {
+        fixture = fixture
+            .file(format!("{dir}/temp{index}_label"), label)
+            .file(format!("{dir}/temp{index}_input"), millis.to_string())
+            .file(format!("{dir}/temp{index}_max"), "100000")
+            .file(format!("{dir}/temp{index}_crit"), "105000");
+    }
+
+    fixture
+}
+
+fn fixture() -> Fixture {
+    let fixture = Fixture::new()
+        .file("proc/cpuinfo", CPU_INFO)
+        .file("sys/class/hwmon/hwmon0/name", "acpitz")
+        .file("sys/class/hwmon/hwmon0/temp1_input", "27800");
+    let fixture = coretemp(
+        fixture,
+        1,
+        &[(1, "Package id 1", 51000), (2, "Core 0", 49000)],
+    );
+    coretemp(
+        fixture,
+        2,
+        &[
+            (1, "Package id 0", 45000),
+            (2, "Core 0", 43000),
+            (6, "Core 4", 44500),
+        ],
+    )
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_coretemp() {
+    let fixture = fixture();
+    let temperatures = SysRoot::from(fixture.path()).temperatures().unwrap();
+    // `hwmon1` is the first coretemp device, but reports `Package id 1`.
+    assert_eq!(
+        temperatures
+            .sockets()
+            .map(|(id, socket)| (id, socket.package().unwrap().label().unwrap()))
+            .collect::<Vec<_>>(),
+        [(0, "Package id 0"), (1, "Package id 1")]
+    );
+    assert_eq!(temperatures.core(1, 0).unwrap().current(), Some(49.0));
+    let package = temperatures.package(1).unwrap();
+    assert_eq!(package.label(), Some("Package id 1"));
+    assert_eq!(package.current(), Some(51.0));
+    assert_eq!(package.max(), Some(100.0));
+    assert_eq!(package.crit(), Some(105.0));
+    assert_eq!(temperatures.core(0, 4).unwrap().current(), Some(44.5));
+    assert_eq!(temperatures.socket(0).unwrap().cores().len(), 2);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cpu() {
+    let temperatures = Temperatures::read_from(fixture().path().join("sys")).unwrap();
+    let cpu_info = CpuInfo::from(CPU_INFO);
+    let current = |processor| {
+        cpu_info
+            .cpu(processor)
+            .unwrap()
+            .temperature(&temperatures)
+            .unwrap()
+            .current()
+    };
+    assert_eq!(current(0), Some(43.0));
+    assert_eq!(current(1), Some(44.5));
+    assert_eq!(current(2), Some(49.0));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_k10temp() {
+    let fixture = Fixture::new()
+        .file("sys/class/hwmon/hwmon3/name", "k10temp")
+        .file("sys/class/hwmon/hwmon3/temp1_label", "Tctl")
+        .file("sys/class/hwmon/hwmon3/temp1_input", "62125")
+        .file("sys/class/hwmon/hwmon3/temp3_label", "Tccd1")
+        .file("sys/class/hwmon/hwmon3/temp3_input", "55000");
+    let temperatures = Temperatures::read_from(fixture.path().join("sys")).unwrap();
+    let socket = temperatures.socket(0).unwrap();
+    assert_eq!(socket.package().unwrap().current(), Some(62.125));
+    assert_eq!(socket.package().unwrap().max(), None);
+    assert!(socket.cores().is_empty());
+    assert_eq!(
+        socket
+            .sensors()
+            .iter()
+            .map(|sensor| sensor.label())
+            .collect::<Vec<_>>(),
+        [Some("Tctl"), Some("Tccd1")]
+    );
+    let cpu_info = CpuInfo::from("processor	: 0\ncore id		: 3");
+    assert_eq!(
+        cpu_info
+            .cpu(0)
+            .unwrap()
+            .temperature(&temperatures)
+            .unwrap()
+            .label(),
+        Some("Tctl")
+    );
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{Caches, CoreType, CoreTypes, CpuInfo, CpuSet, SysRoot};
+
+/// One P-core with two threads and two E-cores.
+const CPU_INFO: &str = "processor	: 0
+core id		: 0
+flags		: fpu hybrid_cpu
+
+processor	: 1
+core id		: 0
+flags		: fpu hybrid_cpu
+
+processor	: 2
+core id		: 8
+flags		: fpu hybrid_cpu
+
+processor	: 3
+core id		: 9
+flags		: fpu hybrid_cpu
+";
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_sysfs() {
+    let fixture = Fixture::new()
+        .file("proc/cpuinfo", CPU_INFO)
+        .file("sys/devices/cpu_core/cpus", "0-1")
+        .file("sys/devices/cpu_atom/cpus", "2-3");
+    let sysroot = SysRoot::from(fixture.path());
+    let core_types = sysroot.core_types().unwrap();
+    assert_eq!(core_types.performance(), CpuSet::from([0, 1]));
+    assert_eq!(core_types.efficiency(), CpuSet::from([2, 3]));
+    let cpu_info = sysroot.cpu_info().unwrap();
+    assert_eq!(
+        cpu_info.cpu(2).unwrap().core_type(&core_types),
+        Some(CoreType::Efficiency)
+    );
+    assert_eq!(
+        core_types
+            .select(&cpu_info, CoreType::Performance)
+            .filter_map(|cpu| cpu.processor())
+            .collect::<Vec<_>>(),
+        [0, 1]
+    );
+}
+
+#[test]
+fn test_guess_smt() {
+    let cpu_info = CpuInfo::from(CPU_INFO);
+    let core_types = CoreTypes::guess(&cpu_info, &cpu_info.topology(), &Caches::default());
+    assert_eq!(core_types.get(0), Some(CoreType::Performance));
+    assert_eq!(core_types.get(3), Some(CoreType::Efficiency));
+    assert_eq!(CoreType::Efficiency.to_string(), "E-core");
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_guess_shared_l2() {
+    // Without SMT, processor 0 and 1 are P-cores of their own.
+    let cpu_info = CPU_INFO.replacen("core id		: 0\n", "core id		: 1\n", 1);
+    let mut fixture = Fixture::new().file("proc/cpuinfo", cpu_info);
+
+    for (cpu, shared) in [(0, "0"), (1, "1"), (2, "2-3"), (3, "2-3")] {
+        let dir = format!("sys/devices/system/cpu/cpu{cpu}/cache/index2");
+        fixture = fixture
+            .file(format!("{dir}/level"), "2")
+            .file(format!("{dir}/type"), "Unified")
+            .file(format!("{dir}/shared_cpu_list"), shared);
+    }
+
+    let core_types = SysRoot::from(fixture.path()).core_types().unwrap();
+    assert_eq!(core_types.performance(), CpuSet::from([0, 1]));
+    assert_eq!(core_types.efficiency(), CpuSet::from([2, 3]));
+}
+
+#[test]
+fn test_not_hybrid() {
+    let cpu_info = CpuInfo::from(CPU_INFO.replace(" hybrid_cpu", ""));
+    let core_types = CoreTypes::guess(&cpu_info, &cpu_info.topology(), &Caches::default());
+    assert!(core_types.is_empty());
+    assert_eq!(
+        cpu_info.cpu(0).and_then(|cpu| cpu.core_type(&core_types)),
+        None
+    );
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{Caches, CpuInfo, CpuSet, LlcDomains, SysRoot};
+
+/// One socket with four cores and two threads each, where two cores each share an L3 cache,
+/// as found on AMD EPYC CPUs with multiple CCXs.
+fn fixture() -> Fixture {
+    let mut cpu_info = String::new();
+    let mut fixture = Fixture::new();
+
+    for cpu in 0..8 {
+        let core = cpu % 4;
+        cpu_info.push_str(&format!(
+            "processor	: {cpu}\nphysical id	: 0\ncore id		: {core}\n\n"
+        ));
+        let ccx = if core < 2 { "0-1,4-5" } else { "2-3,6-7" };
+
+        for (index, level, kind, shared) in [
+            (0, 1, "Data", format!("{core},{}", core + 4)),
+            (1, 1, "Instruction", format!("{core},{}", core + 4)),
+            (3, 3, "Unified", ccx.to_string()),
+        ] {
+            let path = format!("sys/devices/system/cpu/cpu{cpu}/cache/index{index}");
+            fixture = fixture
+                .file(format!("{path}/level"), level.to_string())
+                .file(format!("{path}/type"), kind)
+                .file(format!("{path}/size"), "32768K")
+                .file(format!("{path}/shared_cpu_list"), shared);
+        }
+    }
+
+    fixture.file("proc/cpuinfo", cpu_info)
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_domains() {
+    let fixture = fixture();
+    let domains = SysRoot::from(fixture.path()).llc_domains().unwrap();
+    assert_eq!(domains.len(), 2);
+    assert_eq!(
+        domains
+            .iter()
+            .map(|domain| domain.cpus().clone())
+            .collect::<Vec<_>>(),
+        [CpuSet::from([0, 1, 4, 5]), CpuSet::from([2, 3, 6, 7])]
+    );
+    let domain = domains.domain_of(6).unwrap();
+    assert_eq!(domain.level(), Some(3));
+    assert_eq!(domain.size(), Some(32 * 1024 * 1024));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_one_per_domain() {
+    let fixture = fixture();
+    let domains = SysRoot::from(fixture.path()).llc_domains().unwrap();
+    assert_eq!(domains.one_per_domain(), [0, 2]);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_partition() {
+    let fixture = fixture();
+    let domains = SysRoot::from(fixture.path()).llc_domains().unwrap();
+    assert_eq!(
+        domains.partition(2),
+        [vec![0, 1], vec![4, 5], vec![2, 3], vec![6, 7]]
+    );
+    assert_eq!(
+        domains.partition(3),
+        [vec![0, 1, 4], vec![5], vec![2, 3, 6], vec![7]]
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_without_caches() {
+    let fixture = fixture();
+    let cpu_info = SysRoot::from(fixture.path()).cpu_info().unwrap();
+    let domains = LlcDomains::new(&cpu_info.topology(), &Caches::default());
+    assert_eq!(domains.len(), 1);
+    assert_eq!(domains.iter().next().unwrap().level(), None);
+    assert_eq!(domains.one_per_domain(), [0]);
+    assert!(LlcDomains::new(&CpuInfo::from("").topology(), &Caches::default()).is_empty());
+}
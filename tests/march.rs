@@ -0,0 +1,89 @@
+use proc_cpuinfo::{CpuInfo, Vendor};
+
+const X86_64_V2: &str =
+    "lm cmov cx8 fpu fxsr mmx sse sse2 cx16 lahf_lm popcnt pni sse4_1 sse4_2 ssse3";
+const X86_64_V3: &str = "avx avx2 bmi1 bmi2 f16c fma abm movbe xsave";
+const X86_64_V4: &str = "avx512f avx512bw avx512cd avx512dq avx512vl";
+
+fn cpu_info(vendor_id: &str, family: u8, model: usize, stepping: usize, flags: &str) -> CpuInfo {
+    CpuInfo::from(format!(
+        "processor	: 0\nvendor_id	: {vendor_id}\ncpu family	: {family}\nmodel		: {model}\n\
+         stepping	: {stepping}\nflags		: {flags}\n"
+    ))
+}
+
+fn march(
+    vendor_id: &str,
+    family: u8,
+    model: usize,
+    stepping: usize,
+    flags: &str,
+) -> Option<&'static str> {
+    cpu_info(vendor_id, family, model, stepping, flags)
+        .cpu(0)
+        .and_then(|cpu| cpu.march())
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_vendor() {
+    let cpu_info = cpu_info("HygonGenuine", 0x18, 1, 1, X86_64_V2);
+    assert_eq!(cpu_info.cpu(0).unwrap().vendor(), Some(Vendor::Hygon));
+    assert_eq!(
+        Vendor::from_vendor_id("  Shanghai  "),
+        Some(Vendor::Zhaoxin)
+    );
+    assert_eq!(Vendor::from_vendor_id("ARM"), None);
+}
+
+#[test]
+fn test_march_intel() {
+    let v3 = format!("{X86_64_V2} {X86_64_V3}");
+    let v4 = format!("{v3} {X86_64_V4}");
+    assert_eq!(march("GenuineIntel", 6, 151, 5, &v3), Some("alderlake"));
+    assert_eq!(
+        march("GenuineIntel", 6, 0x6a, 6, &v4),
+        Some("icelake-server")
+    );
+    assert_eq!(
+        march("GenuineIntel", 6, 0x55, 4, &v4),
+        Some("skylake-avx512")
+    );
+    assert_eq!(march("GenuineIntel", 6, 0x55, 7, &v4), Some("cascadelake"));
+}
+
+#[test]
+fn test_march_amd() {
+    let v3 = format!("{X86_64_V2} {X86_64_V3}");
+    let v4 = format!("{v3} {X86_64_V4}");
+    assert_eq!(march("AuthenticAMD", 0x17, 0x01, 1, &v3), Some("znver1"));
+    assert_eq!(march("AuthenticAMD", 0x17, 0x31, 0, &v3), Some("znver2"));
+    assert_eq!(march("AuthenticAMD", 0x19, 0x21, 0, &v3), Some("znver3"));
+    assert_eq!(march("AuthenticAMD", 0x19, 0x61, 2, &v4), Some("znver4"));
+    assert_eq!(march("HygonGenuine", 0x18, 0x01, 1, &v3), Some("znver1"));
+}
+
+#[test]
+fn test_march_zhaoxin() {
+    let v3 = format!("{X86_64_V2} {X86_64_V3}");
+    assert_eq!(
+        march("  Shanghai  ", 7, 0x3b, 0, X86_64_V2),
+        Some("lujiazui")
+    );
+    assert_eq!(march("CentaurHauls", 7, 0x5b, 0, &v3), Some("yongfeng"));
+    assert_eq!(
+        march("  Shanghai  ", 7, 0x5b, 0, X86_64_V2),
+        Some("x86-64-v2")
+    );
+}
+
+#[test]
+fn test_march_fallback() {
+    let v3 = format!("{X86_64_V2} {X86_64_V3}");
+    assert_eq!(march("GenuineIntel", 6, 0x6a, 6, &v3), Some("x86-64-v3"));
+    assert_eq!(
+        march("GenuineIntel", 6, 0xff, 0, X86_64_V2),
+        Some("x86-64-v2")
+    );
+    assert_eq!(march("GenuineIntel", 6, 151, 5, "fpu sse sse2"), None);
+}
@@ -0,0 +1,87 @@
+use proc_cpuinfo::{CpuInfo, Microarchitecture, Vendor};
+
+fn microarchitecture(
+    vendor_id: &str,
+    family: u8,
+    model: usize,
+    stepping: usize,
+) -> Option<Microarchitecture> {
+    CpuInfo::from(format!(
+        "processor	: 0\nvendor_id	: {vendor_id}\ncpu family	: {family}\nmodel		: {model}\n\
+         stepping	: {stepping}\n"
+    ))
+    .cpu(0)
+    .and_then(|cpu| cpu.microarchitecture())
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_intel() {
+    let microarchitecture = microarchitecture("GenuineIntel", 6, 151, 5).unwrap();
+    assert_eq!(microarchitecture.vendor(), Vendor::Intel);
+    assert_eq!(microarchitecture.name(), "Alder Lake");
+    assert_eq!(microarchitecture.codename(), "Alder Lake-S");
+    assert_eq!(
+        microarchitecture.to_string(),
+        "Intel Alder Lake (Alder Lake-S)"
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_intel_stepping() {
+    assert_eq!(
+        microarchitecture("GenuineIntel", 6, 0x55, 4)
+            .unwrap()
+            .name(),
+        "Skylake"
+    );
+    assert_eq!(
+        microarchitecture("GenuineIntel", 6, 0x55, 7)
+            .unwrap()
+            .name(),
+        "Cascade Lake"
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_amd() {
+    let microarchitecture = microarchitecture("AuthenticAMD", 0x19, 0x21, 0).unwrap();
+    assert_eq!(microarchitecture.vendor(), Vendor::Amd);
+    assert_eq!(microarchitecture.name(), "Zen 3");
+    assert_eq!(microarchitecture.codename(), "Vermeer");
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_hygon_and_zhaoxin() {
+    assert_eq!(
+        microarchitecture("HygonGenuine", 0x18, 1, 1)
+            .unwrap()
+            .codename(),
+        "Dhyana"
+    );
+    assert_eq!(
+        microarchitecture("CentaurHauls", 7, 0x1b, 0)
+            .unwrap()
+            .name(),
+        "WuDaoKou"
+    );
+    assert_eq!(
+        microarchitecture("  Shanghai  ", 7, 0x5b, 0)
+            .unwrap()
+            .name(),
+        "YongFeng"
+    );
+    let microarchitecture = microarchitecture("  Shanghai  ", 7, 0x3b, 0).unwrap();
+    assert_eq!(microarchitecture.vendor(), Vendor::Zhaoxin);
+    assert_eq!(microarchitecture.name(), "LuJiaZui");
+    assert_eq!(microarchitecture.codename(), "KX-6000");
+}
+
+#[test]
+fn test_unknown() {
+    assert_eq!(microarchitecture("GenuineIntel", 6, 0xff, 0), None);
+    assert_eq!(microarchitecture("ARM", 6, 151, 5), None);
+}
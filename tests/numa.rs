@@ -0,0 +1,73 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, CpuSet, Numa, SysRoot};
+
+const CPU_INFO: &str = "processor	: 0
+physical id	: 0
+
+processor	: 1
+physical id	: 0
+
+processor	: 2
+physical id	: 1
+
+processor	: 3
+physical id	: 1
+";
+
+fn fixture() -> Fixture {
+    Fixture::new()
+        .file("sys/devices/system/node/online", "0-1")
+        .file("sys/devices/system/node/node0/cpulist", "0-1")
+        .file("sys/devices/system/node/node0/distance", "10 21")
+        .file(
+            "sys/devices/system/node/node0/meminfo",
+            "Node 0 MemTotal:       32768000 kB\nNode 0 MemFree:        16384000 kB",
+        )
+        .file("sys/devices/system/node/node1/cpulist", "2-3")
+        .file("sys/devices/system/node/node1/distance", "21 10")
+        .file(
+            "sys/devices/system/node/node1/meminfo",
+            "Node 1 MemTotal:       16384000 kB\nNode 1 MemFree:         8192000 kB",
+        )
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_nodes() {
+    let fixture = fixture();
+    let numa = SysRoot::from(fixture.path()).numa().unwrap();
+    assert_eq!(numa.nodes().count(), 2);
+    let node = numa.node(1).unwrap();
+    assert_eq!(node.cpus(), &CpuSet::from([2, 3]));
+    assert_eq!(node.mem_total(), Some(16_384_000 * 1024));
+    assert_eq!(node.mem_free(), Some(8_192_000 * 1024));
+    assert_eq!(numa.node_of(1).unwrap().id(), 0);
+    assert!(numa.node_of(4).is_none());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_distances() {
+    let numa = Numa::read_from(fixture().path().join("sys")).unwrap();
+    assert_eq!(numa.distance(0, 1), Some(21));
+    assert_eq!(numa.distance(1, 1), Some(10));
+    assert_eq!(numa.distance(0, 2), None);
+    assert_eq!(numa.distance_matrix(), [[10, 21], [21, 10]]);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_select() {
+    let numa = Numa::read_from(fixture().path().join("sys")).unwrap();
+    let cpu_info = CpuInfo::from(CPU_INFO);
+    assert_eq!(
+        numa.node(1)
+            .unwrap()
+            .select(&cpu_info)
+            .map(|cpu| cpu.physical_id())
+            .collect::<Vec<_>>(),
+        [Some(1), Some(1)]
+    );
+}
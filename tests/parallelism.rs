@@ -0,0 +1,103 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, CpuSet, Numa, Parallelism, SysRoot};
+use std::collections::BTreeMap;
+
+/// Two sockets with two cores and two threads each.
+const CPU_INFO: &str = "processor	: 0
+physical id	: 0
+core id		: 0
+
+processor	: 1
+physical id	: 0
+core id		: 1
+
+processor	: 2
+physical id	: 1
+core id		: 0
+
+processor	: 3
+physical id	: 1
+core id		: 1
+
+processor	: 4
+physical id	: 0
+core id		: 0
+
+processor	: 5
+physical id	: 0
+core id		: 1
+
+processor	: 6
+physical id	: 1
+core id		: 0
+
+processor	: 7
+physical id	: 1
+core id		: 1
+";
+
+fn fixture() -> Fixture {
+    Fixture::new()
+        .file("proc/cpuinfo", CPU_INFO)
+        .file("proc/self/status", "Cpus_allowed_list:	0-7")
+        .file("sys/devices/system/node/node0/cpulist", "0-1,4-5")
+        .file("sys/devices/system/node/node1/cpulist", "2-3,6-7")
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_unrestricted() {
+    let fixture = fixture().file("proc/self/cgroup", "0::/");
+    let parallelism = SysRoot::from(fixture.path()).parallelism().unwrap();
+    assert_eq!(parallelism.quota(), None);
+    assert_eq!(parallelism.threads(), 8);
+    assert_eq!(parallelism.cores(), 4);
+    assert_eq!(
+        parallelism.node_threads(),
+        &BTreeMap::from([(0, 4), (1, 4)])
+    );
+    assert_eq!(parallelism.node_cores(), &BTreeMap::from([(0, 2), (1, 2)]));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cgroup_v2_quota() {
+    let fixture = fixture()
+        .file("proc/self/cgroup", "0::/app")
+        .file("sys/fs/cgroup/cpu.max", "max 100000")
+        .file("sys/fs/cgroup/app/cpu.max", "250000 100000");
+    let sysroot = SysRoot::from(fixture.path());
+    assert_eq!(sysroot.cpu_quota().unwrap(), Some(2.5));
+    let parallelism = sysroot.parallelism().unwrap();
+    assert_eq!(parallelism.threads(), 3);
+    assert_eq!(parallelism.cores(), 3);
+    assert_eq!(
+        parallelism.node_threads(),
+        &BTreeMap::from([(0, 3), (1, 3)])
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cgroup_v1_quota() {
+    let fixture = fixture()
+        .file("proc/self/cgroup", "2:cpu,cpuacct:/app\n0::/")
+        .file("sys/fs/cgroup/cpu/cpu.cfs_quota_us", "-1")
+        .file("sys/fs/cgroup/cpu/cpu.cfs_period_us", "100000")
+        .file("sys/fs/cgroup/cpu/app/cpu.cfs_quota_us", "50000")
+        .file("sys/fs/cgroup/cpu/app/cpu.cfs_period_us", "100000");
+    let sysroot = SysRoot::from(fixture.path());
+    assert_eq!(sysroot.cpu_quota().unwrap(), Some(0.5));
+    assert_eq!(sysroot.parallelism().unwrap().threads(), 1);
+}
+
+#[test]
+fn test_affinity() {
+    let topology = CpuInfo::from(CPU_INFO).topology();
+    let parallelism = Parallelism::new(&topology, &CpuSet::from([0, 4, 2]), &Numa::default(), None);
+    assert_eq!(parallelism.threads(), 3);
+    assert_eq!(parallelism.cores(), 2);
+    assert_eq!(parallelism.node_threads(), &BTreeMap::from([(0, 3)]));
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, Numa, ProcStat, SysRoot};
+use std::collections::BTreeMap;
+
+/// Two sockets with one core and two threads each.
+const CPU_INFO: &str = "processor	: 0
+physical id	: 0
+core id		: 0
+
+processor	: 1
+physical id	: 1
+core id		: 0
+
+processor	: 2
+physical id	: 0
+core id		: 0
+
+processor	: 3
+physical id	: 1
+core id		: 0
+";
+
+fn stat(lines: &[&str]) -> Fixture {
+    Fixture::new()
+        .file("proc/cpuinfo", CPU_INFO)
+        .file(
+            "proc/stat",
+            format!(
+                "cpu  1000 0 1000 8000 0 0 0 0 0 0\n{}\nintr 12345 0 0\nctxt 6789",
+                lines.join("\n")
+            ),
+        )
+        .file("sys/devices/system/node/node0/cpulist", "0-1")
+        .file("sys/devices/system/node/node1/cpulist", "2-3")
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_read() {
+    let fixture = stat(&[
+        "cpu0 10 1 2 30 4 5 6 7 0 0",
+        "cpu1 1 2 3 4",
+        "cpu3 0 0 0 0 0 0 0 0 0 0",
+    ]);
+    let proc_stat = SysRoot::from(fixture.path()).proc_stat().unwrap();
+    assert_eq!(
+        proc_stat
+            .iter()
+            .map(|(processor, _)| processor)
+            .collect::<Vec<_>>(),
+        [0, 1, 3]
+    );
+    let times = proc_stat.get(0).unwrap();
+    assert_eq!(times.user(), 10);
+    assert_eq!(times.nice(), 1);
+    assert_eq!(times.system(), 2);
+    assert_eq!(times.idle(), 30);
+    assert_eq!(times.iowait(), 4);
+    assert_eq!(times.irq(), 5);
+    assert_eq!(times.softirq(), 6);
+    assert_eq!(times.steal(), 7);
+    assert_eq!(times.busy(), 31);
+    assert_eq!(times.total(), 65);
+    assert_eq!(proc_stat.get(1).unwrap().steal(), 0);
+}
+
+#[test]
+fn test_invalid() {
+    let fixture = stat(&["cpu0 10 x 2 30"]);
+    assert!(SysRoot::from(fixture.path()).proc_stat().is_err());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_utilization() {
+    let earlier = stat(&[
+        "cpu0 100 0 0 100 0 0 0 0",
+        "cpu1 100 0 0 100 0 0 0 0",
+        "cpu2 100 0 0 100 0 0 0 0",
+        "cpu3 100 0 0 100 0 0 0 0",
+    ]);
+    let later = stat(&[
+        "cpu0 190 0 0 110 0 0 0 0",
+        "cpu1 100 0 10 190 0 0 0 0",
+        "cpu2 150 0 0 150 0 0 0 0",
+        "cpu3 100 0 0 100 0 0 0 0",
+    ]);
+    let earlier = ProcStat::read_from(earlier.path().join("proc/stat")).unwrap();
+    let utilization = ProcStat::read_from(later.path().join("proc/stat"))
+        .unwrap()
+        .utilization_since(&earlier);
+    assert_eq!(
+        utilization.processors(),
+        BTreeMap::from([(0, 0.9), (1, 0.1), (2, 0.5)])
+    );
+    assert_eq!(utilization.get(3).unwrap().total(), 0);
+
+    let topology = CpuInfo::from(CPU_INFO).topology();
+    let cores = utilization.cores(&topology);
+    assert_eq!(cores[&(0, None, None, 0)].busy(), 140);
+    assert_eq!(cores[&(0, None, None, 0)].utilization(), Some(0.7));
+    assert_eq!(cores[&(1, None, None, 0)].utilization(), Some(0.1));
+    let sockets = utilization.sockets(&topology);
+    assert_eq!(sockets[&0].total(), 200);
+    assert_eq!(sockets[&1].utilization(), Some(0.1));
+
+    let numa = Numa::read_from(later.path().join("sys")).unwrap();
+    let nodes = utilization.nodes(&numa);
+    assert_eq!(nodes[&0].utilization(), Some(0.5));
+    assert_eq!(nodes[&1].utilization(), Some(0.5));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_clusters() {
+    let mut fixture = stat(&[
+        "cpu0 10 0 0 90 0 0 0 0",
+        "cpu1 20 0 0 80 0 0 0 0",
+        "cpu2 30 0 0 70 0 0 0 0",
+        "cpu3 40 0 0 60 0 0 0 0",
+    ]);
+
+    // Two clusters of two cores each, reusing the core IDs.
+    for processor in 0..4 {
+        let dir = format!("sys/devices/system/cpu/cpu{processor}/topology");
+        fixture = fixture
+            .file(format!("{dir}/physical_package_id"), "0")
+            .file(format!("{dir}/cluster_id"), (processor / 2).to_string())
+            .file(format!("{dir}/core_id"), (processor % 2).to_string());
+    }
+
+    let earlier = stat(&[
+        "cpu0 0 0 0 0",
+        "cpu1 0 0 0 0",
+        "cpu2 0 0 0 0",
+        "cpu3 0 0 0 0",
+    ]);
+    let earlier = ProcStat::read_from(earlier.path().join("proc/stat")).unwrap();
+    let sysroot = SysRoot::from(fixture.path());
+    let utilization = sysroot.proc_stat().unwrap().utilization_since(&earlier);
+    let cores = utilization.cores(&sysroot.topology().unwrap());
+    assert_eq!(
+        cores
+            .into_iter()
+            .map(|(core, times)| (core, times.busy()))
+            .collect::<BTreeMap<_, _>>(),
+        BTreeMap::from([
+            ((0, None, Some(0), 0), 10),
+            ((0, None, Some(0), 1), 20),
+            ((0, None, Some(1), 0), 30),
+            ((0, None, Some(1), 1), 40),
+        ])
+    );
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{RaplSnapshot, SysRoot};
+
+fn zone(fixture: Fixture, id: &str, name: &str, energy_uj: u64) -> Fixture {
+    let dir = format!("sys/class/powercap/intel-rapl:{id}");
+    fixture
+        .file(format!("{dir}/name"), name)
+        .file(format!("{dir}/energy_uj"), energy_uj.to_string())
+        .file(format!("{dir}/max_energy_range_uj"), "262143328850")
+}
+
+fn fixture(package0: u64, package1: u64) -> Fixture {
+    let fixture = Fixture::new()
+        .file("sys/class/powercap/intel-rapl/enabled", "1")
+        .file("sys/class/powercap/intel-rapl-mmio:0/name", "package-0");
+    let fixture = zone(fixture, "0", "package-0", package0);
+    let fixture = zone(fixture, "0:0", "core", 1000);
+    let fixture = zone(fixture, "1", "package-1", package1);
+    let fixture = zone(fixture, "1:0", "dram", 2000);
+    zone(fixture, "2", "psys", 3000)
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_zones() {
+    let fixture = fixture(5_000_000, 7_000_000);
+    let rapl = SysRoot::from(fixture.path()).rapl().unwrap();
+    assert_eq!(
+        rapl.zones()
+            .iter()
+            .map(|zone| (zone.id(), zone.name(), zone.socket()))
+            .collect::<Vec<_>>(),
+        [
+            ("0", "package-0", Some(0)),
+            ("0:0", "core", Some(0)),
+            ("1", "package-1", Some(1)),
+            ("1:0", "dram", Some(1)),
+            ("2", "psys", None),
+        ]
+    );
+    let zone = rapl.zone("0").unwrap();
+    assert!(zone.is_package());
+    assert_eq!(zone.energy_uj(), 5_000_000);
+    assert_eq!(zone.max_energy_range_uj(), 262_143_328_850);
+    assert_eq!(zone.die(), None);
+    assert_eq!(rapl.packages().keys().copied().collect::<Vec<_>>(), [0, 1]);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_physical_ids() {
+    let fixture = Fixture::new();
+    let fixture = zone(fixture, "0", "package-1", 0);
+    let fixture = zone(fixture, "0:0", "dram", 0);
+    let fixture = zone(fixture, "1", "package-3", 0);
+    let rapl = RaplSnapshot::read_from(fixture.path().join("sys")).unwrap();
+    assert_eq!(rapl.packages().keys().copied().collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(rapl.zone("0:0").unwrap().socket(), Some(1));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_dies() {
+    let dies = |energy_uj: [u64; 3]| {
+        let fixture = Fixture::new();
+        let fixture = zone(fixture, "0", "package-0-die-0", energy_uj[0]);
+        let fixture = zone(fixture, "0:0", "core", 0);
+        let fixture = zone(fixture, "1", "package-0-die-1", energy_uj[1]);
+        zone(fixture, "2", "package-1-die-0", energy_uj[2])
+    };
+    let earlier = RaplSnapshot::read_from(dies([1_000, 2_000, 3_000]).path().join("sys")).unwrap();
+    let later = RaplSnapshot::read_from(dies([1_500, 2_700, 3_100]).path().join("sys")).unwrap();
+    assert_eq!(
+        later
+            .zones()
+            .iter()
+            .map(|zone| (zone.id(), zone.socket(), zone.die()))
+            .collect::<Vec<_>>(),
+        [
+            ("0", Some(0), Some(0)),
+            ("0:0", Some(0), Some(0)),
+            ("1", Some(0), Some(1)),
+            ("2", Some(1), Some(0)),
+        ]
+    );
+    assert_eq!(later.packages()[&0].len(), 2);
+    assert_eq!(
+        later.energy_since(&earlier),
+        [(0, 1_200), (1, 100)].into_iter().collect()
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_energy_since() {
+    let earlier = fixture(5_000_000, 262_143_000_000);
+    let earlier = RaplSnapshot::read_from(earlier.path().join("sys")).unwrap();
+    let later = fixture(8_000_000, 671_150);
+    let later = RaplSnapshot::read_from(later.path().join("sys")).unwrap();
+    let energy = later.energy_since(&earlier);
+    assert_eq!(energy.get(&0), Some(&3_000_000));
+    assert_eq!(energy.get(&1), Some(&1_000_000));
+
+    let seconds = later.time().duration_since(earlier.time()).as_secs_f64();
+    let power = later.power_since(&earlier);
+    assert!((power[&0] * seconds - 3.0).abs() < 1e-6);
+    assert!((power[&1] * seconds - 1.0).abs() < 1e-6);
+}
@@ -0,0 +1,112 @@
+use proc_cpuinfo::{CpuInfo, ParseRequirementError, Requirement};
+use std::str::FromStr;
+
+const CPU_INFO: &str = "processor	: 0
+vendor_id	: GenuineIntel
+flags		: fpu sse sse2 avx avx2 avx_vnni vmx
+vmx flags	: ept vpid
+bugs		: spectre_v1 spectre_v2
+
+processor	: 1
+vendor_id	: GenuineIntel
+flags		: fpu sse sse2 avx avx2 vmx
+vmx flags	: ept vpid
+bugs		: spectre_v1 spectre_v2 tsx_async_abort
+";
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_parse() {
+    assert_eq!(
+        Requirement::from_str("avx2 && (avx512f || avx_vnni) && !tsx_async_abort bug").unwrap(),
+        Requirement::And(vec![
+            Requirement::Flag("avx2".to_string()),
+            Requirement::Or(vec![
+                Requirement::Flag("avx512f".to_string()),
+                Requirement::Flag("avx_vnni".to_string()),
+            ]),
+            Requirement::Not(Box::new(Requirement::Bug("tsx_async_abort".to_string()))),
+        ])
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_parse_vmx_flag() {
+    assert_eq!(
+        Requirement::from_str("vmx && ept vmx").unwrap(),
+        Requirement::And(vec![
+            Requirement::Flag("vmx".to_string()),
+            Requirement::VmxFlag("ept".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Requirement::from_str("avx2 & sse"),
+        Err(ParseRequirementError::InvalidCharacter('&', 5))
+    );
+    assert_eq!(
+        Requirement::from_str("(avx2 || sse"),
+        Err(ParseRequirementError::UnexpectedEnd)
+    );
+    assert_eq!(
+        Requirement::from_str("avx2 sse"),
+        Err(ParseRequirementError::UnexpectedToken("sse".to_string(), 5))
+    );
+    assert_eq!(
+        Requirement::from_str(&("!".repeat(1_000_000) + "avx")),
+        Err(ParseRequirementError::TooDeep(64))
+    );
+    assert_eq!(
+        Requirement::from_str(&"(".repeat(1_000_000)),
+        Err(ParseRequirementError::TooDeep(64))
+    );
+    assert!(Requirement::from_str(&format!("{}avx{}", "(".repeat(64), ")".repeat(64))).is_ok());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_display() {
+    let expr = "avx2 && (avx512f || avx_vnni) && !tsx_async_abort bug";
+    assert_eq!(Requirement::from_str(expr).unwrap().to_string(), expr);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_check() {
+    let cpu_info = CpuInfo::from_str(CPU_INFO).unwrap();
+    let requirement =
+        Requirement::from_str("avx2 && (avx512f || avx_vnni) && !tsx_async_abort bug").unwrap();
+    assert!(requirement.is_satisfied_by(&cpu_info.cpu(0).unwrap()));
+    let error = requirement.check(&cpu_info.cpu(1).unwrap()).unwrap_err();
+    assert_eq!(error.processor(), Some(1));
+    assert_eq!(
+        error.terms(),
+        ["avx512f", "avx_vnni", "!tsx_async_abort bug"]
+    );
+    assert_eq!(
+        error.to_string(),
+        "processor 1 does not satisfy: avx512f, avx_vnni, !tsx_async_abort bug"
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_check_all() {
+    let cpu_info = CpuInfo::from_str(CPU_INFO).unwrap();
+    assert!(Requirement::from_str("avx2 && ept vmx && spectre_v1 bug")
+        .unwrap()
+        .check_all(&cpu_info)
+        .is_ok());
+    assert_eq!(
+        Requirement::from_str("!tsx_async_abort bug")
+            .unwrap()
+            .check_all(&cpu_info)
+            .unwrap_err()
+            .processor(),
+        Some(1)
+    );
+}
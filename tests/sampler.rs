@@ -0,0 +1,100 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{
+    Clock, CpuMhzSource, Frequencies, FrequencySampler, FrequencySource, ScalingCurFreqSource,
+    SysRoot,
+};
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::time::Duration;
+
+const INTERVAL: Duration = Duration::from_millis(100);
+
+/// A clock which only advances when sleeping.
+#[derive(Default)]
+struct FakeClock(Duration);
+
+impl Clock for FakeClock {
+    fn now(&mut self) -> Duration {
+        self.0
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        self.0 += duration;
+    }
+}
+
+/// Yields the given frequencies of processor 0 and a constant 3000 MHz for processor 1.
+fn source(frequencies: &[f64]) -> impl FnMut() -> Result<Frequencies, Error> + '_ {
+    let mut frequencies = frequencies.iter();
+    move || {
+        Ok(BTreeMap::from([
+            (0, frequencies.next().copied().unwrap_or_default()),
+            (1, 3000.0),
+        ]))
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_sample() {
+    let frequencies = [3000.0, 1000.0, 4000.0, 2000.0, 5000.0];
+    let mut sampler = FrequencySampler::new(FakeClock::default(), source(&frequencies), INTERVAL);
+    let series = sampler.sample(5).unwrap();
+    assert_eq!(series.len(), 5);
+    assert_eq!(
+        series.processor(0),
+        [
+            (Duration::ZERO, 3000.0),
+            (INTERVAL, 1000.0),
+            (INTERVAL * 2, 4000.0),
+            (INTERVAL * 3, 2000.0),
+            (INTERVAL * 4, 5000.0),
+        ]
+    );
+    let stats = series.stats(0).unwrap();
+    assert_eq!(stats.count(), 5);
+    assert_eq!(stats.min(), 1000.0);
+    assert_eq!(stats.max(), 5000.0);
+    assert_eq!(stats.mean(), 3000.0);
+    assert_eq!(stats.percentile(50.0), 3000.0);
+    assert_eq!(stats.percentile(90.0), 5000.0);
+    assert_eq!(stats.percentile(0.0), 1000.0);
+    assert_eq!(series.all_stats()[&1].max(), 3000.0);
+    assert!(series.stats(2).is_none());
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_sample_for() {
+    let mut sampler = FrequencySampler::new(FakeClock::default(), source(&[]), INTERVAL);
+    let series = sampler.sample_for(Duration::from_millis(450)).unwrap();
+    assert_eq!(series.len(), 5);
+    assert_eq!(series.iter().last().unwrap().0, INTERVAL * 4);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_sources() {
+    let fixture = Fixture::new()
+        .file(
+            "proc/cpuinfo",
+            "processor	: 0\ncpu MHz		: 799.932\n\nprocessor	: 1\ncpu MHz		: 4400.000",
+        )
+        .file(
+            "sys/devices/system/cpu/cpu0/cpufreq/scaling_cur_freq",
+            "800000",
+        )
+        .file(
+            "sys/devices/system/cpu/cpu1/cpufreq/scaling_cur_freq",
+            "4400000",
+        );
+    let sysroot = SysRoot::from(fixture.path());
+    let cpu_mhz = CpuMhzSource::new(sysroot.clone()).frequencies().unwrap();
+    assert!((cpu_mhz[&0] - 799.932).abs() < 0.001);
+    assert_eq!(
+        ScalingCurFreqSource::new(sysroot).frequencies().unwrap(),
+        BTreeMap::from([(0, 800.0), (1, 4400.0)])
+    );
+}
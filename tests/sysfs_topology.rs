@@ -0,0 +1,99 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, CpuSet, SysfsTopology, Topology};
+
+const ARM_CPU_INFO: &str = "processor	: 0
+CPU part	: 0xd05
+
+processor	: 1
+CPU part	: 0xd05
+
+processor	: 2
+CPU part	: 0xd0b
+
+processor	: 3
+CPU part	: 0xd0b
+";
+
+/// Two clusters of two cores each, as found on ARM big.LITTLE systems.
+fn arm_sysfs() -> Fixture {
+    let mut fixture = Fixture::new();
+
+    for cpu in 0..4 {
+        let topology = format!("devices/system/cpu/cpu{cpu}/topology");
+        fixture = fixture
+            .file(format!("{topology}/physical_package_id"), "0")
+            .file(format!("{topology}/die_id"), "-1")
+            .file(format!("{topology}/cluster_id"), (cpu / 2).to_string())
+            .file(format!("{topology}/core_id"), (cpu % 2).to_string())
+            .file(format!("{topology}/thread_siblings_list"), cpu.to_string())
+            .file(format!("{topology}/core_cpus_list"), cpu.to_string());
+    }
+
+    fixture
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_read() {
+    let fixture = arm_sysfs().file("devices/system/cpu/cpu4/online", "0");
+    let sysfs = SysfsTopology::read_from(fixture.path()).unwrap();
+    assert_eq!(sysfs.iter().count(), 4);
+    let cpu = sysfs.get(3).unwrap();
+    assert_eq!(cpu.physical_package_id(), Some(0));
+    assert_eq!(cpu.die_id(), None);
+    assert_eq!(cpu.cluster_id(), Some(1));
+    assert_eq!(cpu.core_id(), Some(1));
+    assert_eq!(cpu.thread_siblings(), &CpuSet::from([3]));
+    assert_eq!(cpu.core_cpus(), &CpuSet::from([3]));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_merged() {
+    let sysfs = SysfsTopology::read_from(arm_sysfs().path()).unwrap();
+    let topology = Topology::merged(&CpuInfo::from(ARM_CPU_INFO), &sysfs);
+    assert_eq!(topology.socket_count(), 1);
+    assert_eq!(topology.die_count(), 0);
+    assert_eq!(topology.cluster_count(), 2);
+    assert_eq!(topology.core_count(), 4);
+    assert_eq!(topology.smt_width(), 1);
+    assert_eq!(topology.core_of(2).unwrap().cluster(), Some(1));
+    assert_eq!(topology.core_of(2).unwrap().id(), 0);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_merged_smt() {
+    let mut fixture = Fixture::new();
+
+    for cpu in 0..4 {
+        let topology = format!("devices/system/cpu/cpu{cpu}/topology");
+        fixture = fixture
+            .file(format!("{topology}/physical_package_id"), "0")
+            .file(format!("{topology}/die_id"), "0")
+            .file(format!("{topology}/core_id"), (cpu % 2).to_string())
+            .file(
+                format!("{topology}/thread_siblings_list"),
+                format!("{},{}", cpu % 2, cpu % 2 + 2),
+            );
+    }
+
+    let sysfs = SysfsTopology::read_from(fixture.path()).unwrap();
+    assert_eq!(
+        sysfs.get(1).unwrap().thread_siblings(),
+        &CpuSet::from([1, 3])
+    );
+    assert_eq!(sysfs.get(1).unwrap().cluster_id(), None);
+    let topology = Topology::merged(&CpuInfo::from(ARM_CPU_INFO), &sysfs);
+    assert_eq!(topology.die_count(), 1);
+    assert_eq!(topology.core_count(), 2);
+    assert_eq!(topology.smt_width(), 2);
+    assert_eq!(topology.siblings(1), Some(CpuSet::from([3])));
+}
+
+#[test]
+fn test_missing_sysfs() {
+    assert!(SysfsTopology::read_from("/nonexistent").is_err());
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::SysRoot;
+use std::path::Path;
+
+const CPU_INFO: &str = "processor	: 0
+physical id	: 0
+core id		: 0
+
+processor	: 1
+physical id	: 0
+core id		: 1
+";
+
+fn fixture() -> Fixture {
+    Fixture::new()
+        .file("proc/cpuinfo", CPU_INFO)
+        .file(
+            "proc/cmdline",
+            "BOOT_IMAGE=/vmlinuz root=/dev/sda1 isolcpus=1",
+        )
+        .file(
+            "sys/devices/system/cpu/cpu0/topology/physical_package_id",
+            "0",
+        )
+        .file("sys/devices/system/cpu/cpu0/topology/die_id", "0")
+        .file("sys/devices/system/cpu/cpu0/topology/core_id", "0")
+        .file(
+            "sys/devices/system/cpu/cpu1/topology/physical_package_id",
+            "0",
+        )
+        .file("sys/devices/system/cpu/cpu1/topology/die_id", "0")
+        .file("sys/devices/system/cpu/cpu1/topology/core_id", "1")
+}
+
+#[test]
+fn test_paths() {
+    let sysroot = SysRoot::new("/tmp/sosreport");
+    assert_eq!(sysroot.proc(), Path::new("/tmp/sosreport/proc"));
+    assert_eq!(sysroot.sys(), Path::new("/tmp/sosreport/sys"));
+    assert_eq!(SysRoot::default().path(), Path::new("/"));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cpu_info() {
+    let fixture = fixture();
+    let sysroot = SysRoot::from(fixture.path());
+    assert_eq!(sysroot.cpu_info().unwrap().cpus().count(), 2);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_cmdline() {
+    let fixture = fixture();
+    assert_eq!(
+        SysRoot::from(fixture.path()).cmdline().unwrap(),
+        "BOOT_IMAGE=/vmlinuz root=/dev/sda1 isolcpus=1"
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_topology() {
+    let fixture = fixture();
+    let sysroot = SysRoot::from(fixture.path());
+    assert_eq!(sysroot.sysfs_topology().unwrap().iter().count(), 2);
+    let topology = sysroot.topology().unwrap();
+    assert_eq!(topology.core_count(), 2);
+    assert_eq!(topology.die_count(), 1);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_topology_without_sysfs() {
+    let fixture = Fixture::new().file("proc/cpuinfo", CPU_INFO);
+    let topology = SysRoot::from(fixture.path()).topology().unwrap();
+    assert_eq!(topology.core_count(), 2);
+    assert_eq!(topology.die_count(), 0);
+}
@@ -0,0 +1,101 @@
+use proc_cpuinfo::{target_feature, CpuInfo, X86_64Level};
+use std::str::FromStr;
+
+const CPU_INFO: &str = "processor	: 0
+vendor_id	: GenuineIntel
+cpu family	: 6
+model		: 151
+model name	: 12th Gen Intel(R) Core(TM) i5-12400
+stepping	: 5
+microcode	: 0x2c
+cpu MHz		: 2500.000
+cache size	: 18432 KB
+physical id	: 0
+siblings	: 12
+core id		: 0
+cpu cores	: 6
+apicid		: 0
+initial apicid	: 0
+fpu		: yes
+fpu_exception	: yes
+cpuid level	: 32
+wp		: yes
+flags		: fpu vme de pse tsc msr pae mce cx8 apic sep mtrr pge mca cmov pat pse36 clflush dts acpi mmx fxsr sse sse2 ss ht tm pbe syscall nx pdpe1gb rdtscp lm constant_tsc art arch_perfmon pebs bts rep_good nopl xtopology nonstop_tsc cpuid aperfmperf tsc_known_freq pni pclmulqdq dtes64 monitor ds_cpl vmx est tm2 ssse3 sdbg fma cx16 xtpr pdcm sse4_1 sse4_2 x2apic movbe popcnt tsc_deadline_timer aes xsave avx f16c rdrand lahf_lm abm 3dnowprefetch cpuid_fault epb cat_l2 cdp_l2 ssbd ibrs ibpb stibp ibrs_enhanced tpr_shadow flexpriority ept vpid ept_ad fsgsbase tsc_adjust bmi1 avx2 smep bmi2 erms invpcid rdt_a rdseed adx smap clflushopt clwb intel_pt sha_ni xsaveopt xsavec xgetbv1 xsaves split_lock_detect avx_vnni dtherm ida arat pln pts hwp hwp_notify hwp_act_window hwp_epp hwp_pkg_req hfi vnmi umip pku ospke waitpkg gfni vaes vpclmulqdq rdpid movdiri movdir64b fsrm md_clear serialize arch_lbr ibt flush_l1d arch_capabilities
+vmx flags	: vnmi preemption_timer posted_intr invvpid ept_x_only ept_ad ept_1gb flexpriority apicv tsc_offset vtpr mtf vapic ept vpid unrestricted_guest vapic_reg vid ple shadow_vmcs pml ept_mode_based_exec tsc_scaling usr_wait_pause
+bugs		: spectre_v1 spectre_v2 spec_store_bypass swapgs eibrs_pbrsb
+bogomips	: 4993.00
+clflush size	: 64
+cache_alignment	: 64
+address sizes	: 39 bits physical, 48 bits virtual
+power management:
+";
+
+#[test]
+fn test_target_feature() {
+    assert_eq!(target_feature("sse4_2"), Some("sse4.2"));
+    assert_eq!(target_feature("pclmulqdq"), Some("pclmulqdq"));
+    assert_eq!(target_feature("sha_ni"), Some("sha"));
+    assert_eq!(target_feature("avx512vnni"), Some("avx512vnni"));
+    assert_eq!(target_feature("avx512_vnni"), Some("avx512vnni"));
+    assert_eq!(target_feature("constant_tsc"), None);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_target_features() {
+    let features = CpuInfo::from_str(CPU_INFO)
+        .unwrap()
+        .cpu(0)
+        .unwrap()
+        .target_features();
+    assert!(features.contains("sse4.2"));
+    assert!(features.contains("sha"));
+    assert!(features.contains("avxvnni"));
+    assert!(!features.contains("avx512f"));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_x86_64_level() {
+    assert_eq!(
+        CpuInfo::from_str(CPU_INFO)
+            .unwrap()
+            .cpu(0)
+            .unwrap()
+            .x86_64_level(),
+        Some(X86_64Level::V3)
+    );
+    assert_eq!(
+        CpuInfo::from_str("processor	: 0\nflags		: fp asimd evtstrm aes pmull sha1 sha2 crc32\n")
+            .unwrap()
+            .cpu(0)
+            .unwrap()
+            .x86_64_level(),
+        None
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_rustflags() {
+    let cpu_info = CpuInfo::from_str(CPU_INFO).unwrap();
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert_eq!(
+        cpu.target_cpu_flags(),
+        Some("-C target-cpu=x86-64-v3".to_string())
+    );
+    assert_eq!(
+        cpu.target_feature_flags(),
+        Some(
+            "-C target-feature=+adx,+aes,+avx,+avx2,+avxvnni,+bmi1,+bmi2,+cmpxchg16b,+ermsb,+f16c,\
+             +fma,+fxsr,+gfni,+lahfsahf,+lzcnt,+movbe,+pclmulqdq,+popcnt,+rdrand,+rdseed,+sha,\
+             +sse,+sse2,+sse3,+sse4.1,+sse4.2,+ssse3,+vaes,+vpclmulqdq,+xsave,+xsavec,+xsaveopt"
+                .to_string()
+        )
+    );
+
+    let cpu_info = CpuInfo::from_str("processor\t: 0\nflags\t\t: fpu vme\n").unwrap();
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert_eq!(cpu.target_cpu_flags(), None);
+    assert_eq!(cpu.target_feature_flags(), None);
+}
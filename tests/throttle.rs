@@ -0,0 +1,161 @@
+mod common;
+
+use common::Fixture;
+use proc_cpuinfo::{CpuInfo, SysRoot, ThrottleSnapshot};
+use std::collections::BTreeMap;
+
+/// One socket with two cores and two threads each.
+const CPU_INFO: &str = "processor	: 0
+physical id	: 0
+core id		: 0
+flags		: fpu dtherm pln pts
+
+processor	: 1
+physical id	: 0
+core id		: 1
+flags		: fpu dtherm pln pts
+
+processor	: 2
+physical id	: 0
+core id		: 0
+flags		: fpu dtherm pln pts
+
+processor	: 3
+physical id	: 0
+core id		: 1
+flags		: fpu dtherm pln pts
+";
+
+fn fixture(core_counts: [u64; 2], package_count: u64) -> Fixture {
+    let mut fixture = Fixture::new().file("proc/cpuinfo", CPU_INFO);
+
+    for processor in 0..4 {
+        let dir = format!("sys/devices/system/cpu/cpu{processor}/thermal_throttle");
+        let core_count = core_counts[processor % 2];
+        fixture = fixture
+            .file(format!("{dir}/core_throttle_count"), core_count.to_string())
+            .file(
+                format!("{dir}/core_throttle_total_time_ms"),
+                (core_count * 10).to_string(),
+            )
+            .file(
+                format!("{dir}/package_throttle_count"),
+                package_count.to_string(),
+            );
+    }
+
+    fixture
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_counters() {
+    let fixture = fixture([3, 5], 7);
+    let sysroot = SysRoot::from(fixture.path());
+    let snapshot = sysroot.throttle().unwrap();
+    let counters = snapshot.get(1).unwrap();
+    assert_eq!(counters.core().unwrap().count(), 5);
+    assert_eq!(counters.core().unwrap().total_time_ms(), Some(50));
+    assert_eq!(counters.package().unwrap().count(), 7);
+    assert_eq!(counters.package().unwrap().total_time_ms(), None);
+
+    let topology = sysroot.topology().unwrap();
+    assert_eq!(
+        snapshot
+            .cores(&topology)
+            .into_iter()
+            .map(|(core, throttle)| (core, throttle.count()))
+            .collect::<BTreeMap<_, _>>(),
+        BTreeMap::from([((0, None, None, 0), 3), ((0, None, None, 1), 5)])
+    );
+    assert_eq!(snapshot.packages(&topology)[&0].count(), 7);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_delta() {
+    let earlier = ThrottleSnapshot::read_from(fixture([3, 5], 7).path().join("sys")).unwrap();
+    let later = ThrottleSnapshot::read_from(fixture([4, 5], 9).path().join("sys")).unwrap();
+    let delta = later.delta(&earlier);
+    let topology = CpuInfo::from(CPU_INFO).topology();
+    let cores = delta.cores(&topology);
+    assert_eq!(cores[&(0, None, None, 0)].count(), 1);
+    assert_eq!(cores[&(0, None, None, 0)].total_time_ms(), Some(10));
+    assert_eq!(cores[&(0, None, None, 1)].count(), 0);
+    assert_eq!(delta.packages(&topology)[&0].count(), 2);
+
+    // Processors 1 to 3 were offline when the earlier snapshot was taken.
+    let fixture = Fixture::new().file(
+        "sys/devices/system/cpu/cpu0/thermal_throttle/core_throttle_count",
+        "2",
+    );
+    let earlier = ThrottleSnapshot::read_from(fixture.path().join("sys")).unwrap();
+    let delta = later.delta(&earlier);
+    assert_eq!(
+        delta
+            .iter()
+            .map(|(processor, _)| processor)
+            .collect::<Vec<_>>(),
+        [0]
+    );
+    assert_eq!(delta.get(0).unwrap().core().unwrap().count(), 2);
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_clusters() {
+    let mut fixture = Fixture::new().file(
+        "proc/cpuinfo",
+        "processor	: 0\n\nprocessor	: 1\n\nprocessor	: 2\n\nprocessor	: 3",
+    );
+
+    // Two clusters of two cores each, reusing the core IDs.
+    for processor in 0..4 {
+        let dir = format!("sys/devices/system/cpu/cpu{processor}");
+        fixture = fixture
+            .file(format!("{dir}/topology/physical_package_id"), "0")
+            .file(
+                format!("{dir}/topology/cluster_id"),
+                (processor / 2).to_string(),
+            )
+            .file(
+                format!("{dir}/topology/core_id"),
+                (processor % 2).to_string(),
+            )
+            .file(
+                format!("{dir}/thermal_throttle/core_throttle_count"),
+                processor.to_string(),
+            );
+    }
+
+    let sysroot = SysRoot::from(fixture.path());
+    let topology = sysroot.topology().unwrap();
+    let cores = sysroot.throttle().unwrap().cores(&topology);
+    assert_eq!(topology.core_count(), 4);
+    assert_eq!(
+        cores
+            .into_iter()
+            .map(|(core, throttle)| (core, throttle.count()))
+            .collect::<BTreeMap<_, _>>(),
+        BTreeMap::from([
+            ((0, None, Some(0), 0), 0),
+            ((0, None, Some(0), 1), 1),
+            ((0, None, Some(1), 0), 2),
+            ((0, None, Some(1), 1), 3),
+        ])
+    );
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_flags() {
+    let cpu_info = CpuInfo::from(CPU_INFO);
+    let cpu = cpu_info.cpu(0).unwrap();
+    assert!(cpu.has_digital_thermal_sensor());
+    assert!(cpu.has_package_thermal_status());
+    assert!(cpu.has_power_limit_notification());
+    assert!(!CpuInfo::from("processor	: 0\nflags		: fpu")
+        .cpu(0)
+        .unwrap()
+        .has_package_thermal_status());
+}
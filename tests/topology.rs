@@ -0,0 +1,80 @@
+use proc_cpuinfo::{CpuInfo, CpuSet, TopologyError};
+
+/// Generates the topology related fields of `/proc/cpuinfo`
+/// for the given amount of sockets, cores per socket and threads per core.
+fn cpu_info(sockets: usize, cores: usize, threads: usize) -> CpuInfo {
+    let mut text = String::new();
+
+    for socket in 0..sockets {
+        for thread in 0..threads {
+            for core in 0..cores {
+                let processor = thread * sockets * cores + socket * cores + core;
+                text.push_str(&format!(
+                    "processor	: {processor}\nphysical id	: {socket}\nsiblings	: {}\n\
+                     core id		: {core}\ncpu cores	: {cores}\n\n",
+                    cores * threads
+                ));
+            }
+        }
+    }
+
+    CpuInfo::from(text)
+}
+
+#[test]
+fn test_counts() {
+    let topology = cpu_info(2, 4, 2).topology();
+    assert_eq!(topology.socket_count(), 2);
+    assert_eq!(topology.core_count(), 8);
+    assert_eq!(topology.processor_count(), 16);
+    assert_eq!(topology.smt_width(), 2);
+    assert_eq!(topology.validate(), Ok(()));
+}
+
+#[allow(clippy::unwrap_used)]
+#[test]
+fn test_sockets() {
+    let topology = cpu_info(2, 4, 2).topology();
+    let socket = topology.socket(1).unwrap();
+    assert_eq!(socket.core_count(), 4);
+    assert_eq!(
+        socket.processors().collect::<CpuSet>(),
+        CpuSet::from([4, 5, 6, 7, 12, 13, 14, 15])
+    );
+    assert_eq!(socket.core(2).unwrap().processors(), &CpuSet::from([6, 14]));
+    assert_eq!(topology.socket_of(13).unwrap().id(), 1);
+}
+
+#[test]
+fn test_siblings() {
+    let topology = cpu_info(2, 4, 2).topology();
+    assert_eq!(topology.siblings(5), Some(CpuSet::from([13])));
+    assert_eq!(topology.siblings(13), Some(CpuSet::from([5])));
+    assert_eq!(topology.siblings(16), None);
+    assert_eq!(
+        cpu_info(1, 4, 1).topology().siblings(0),
+        Some(CpuSet::new())
+    );
+}
+
+#[test]
+fn test_validate() {
+    let text = "processor	: 0\nphysical id	: 0\nsiblings	: 4\ncore id		: 0\ncpu cores	: 2\n\n\
+                processor	: 1\nphysical id	: 0\nsiblings	: 4\ncore id		: 0\ncpu cores	: 2\n";
+    assert_eq!(
+        CpuInfo::from(text).topology().validate(),
+        Err(TopologyError::Siblings {
+            socket: 0,
+            expected: 4,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+fn test_missing_ids() {
+    let topology = CpuInfo::from("processor	: 0\n\nprocessor	: 1\n").topology();
+    assert_eq!(topology.socket_count(), 1);
+    assert_eq!(topology.core_count(), 2);
+    assert_eq!(topology.smt_width(), 1);
+}